@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::sync::Arc;
 use std::{sync::atomic::AtomicUsize, thread, time::Duration};
 
@@ -7,8 +8,12 @@ use axum::{
 use serde::Serialize;
 use tournament::tournament_result::{TournamentResult};
 use tournament::tournament::run_tournament;
-use tournament::factories::game_config_factory::{ConfigFactory};
+use tournament::factories::game_config_factory::{ConfigFactory, GameConfig};
 use bots::available_bots;
+use bots::wasm::wasm_bot::{WasmBot, DEFAULT_FUEL_PER_MOVE};
+use game::bot::bot::BotConstructor;
+use game::map::structs::map_layout::MapLayout;
+use game::map::structs::time_budget::TimeBudget;
 
 
 pub async fn run_tournament_handler() -> impl IntoResponse {
@@ -28,10 +33,75 @@ pub async fn run_tournament_handler() -> impl IntoResponse {
 
 
 
+/// Directory with sandboxed `.wasm` bots that join every tournament, set with `WASM_BOT_DIR`.
+fn wasm_bot_dir() -> PathBuf {
+    std::env::var("WASM_BOT_DIR").map_or_else(|_| PathBuf::from("wasm_bots"), PathBuf::from)
+}
+
+fn bot_constructors() -> Result<Vec<BotConstructor>, String> {
+    let mut constructors = available_bots();
+    let dir = wasm_bot_dir();
+    if dir.is_dir() {
+        constructors.extend(WasmBot::load_dir(&dir, DEFAULT_FUEL_PER_MOVE)?);
+    }
+    Ok(constructors)
+}
+
+/// Directory with `.map` files to play the tournament on, set with `MAP_DIR`.
+fn map_dir() -> Option<PathBuf> {
+    std::env::var("MAP_DIR").ok().map(PathBuf::from)
+}
+
+/// Configs for the maps in `map_dir`, or the generated maps when it is not set.
+fn tournament_configs() -> Result<Vec<GameConfig>, String> {
+    let Some(dir) = map_dir() else {
+        return Ok(ConfigFactory::generate_tournament_configs());
+    };
+    let mut layouts = Vec::new();
+    for entry in std::fs::read_dir(&dir).map_err(|err| format!("Cannot read {}: {err}", dir.display()))? {
+        let path = entry.map_err(|err| err.to_string())?.path();
+        if path.extension().is_some_and(|extension| extension == "map") {
+            layouts.push(MapLayout::load(&path)?);
+        }
+    }
+    if layouts.is_empty() {
+        return Err(format!("No .map files in {}", dir.display()));
+    }
+    let configs = ConfigFactory::generate_layout_configs(&layouts);
+    for config in &configs {
+        // A bad map would otherwise panic inside a tournament thread
+        config.validate().map_err(|err| {
+            format!("Map {} cannot be played by {} players: {err}", config.layout.as_ref().map_or("Custom", |layout| layout.name()), config.num_players)
+        })?;
+    }
+    Ok(configs)
+}
+
+/// Time budget of every game, set in milliseconds with `MOVE_TIME_MS` and `GAME_TIME_MS` where 0
+/// means no limit. The tournament budget when they are not set.
+fn time_budget() -> Result<TimeBudget, String> {
+    let default = ConfigFactory::tournament_time_budget();
+    let limit = |variable: &str, default: Option<Duration>| -> Result<Option<Duration>, String> {
+        let Ok(value) = std::env::var(variable) else {
+            return Ok(default);
+        };
+        let millis: u64 = value.parse().map_err(|_| format!("{variable} must be a number of milliseconds, not {value}"))?;
+        Ok((millis > 0).then(|| Duration::from_millis(millis)))
+    };
+    Ok(TimeBudget {
+        per_move: limit("MOVE_TIME_MS", default.per_move)?,
+        per_game: limit("GAME_TIME_MS", default.per_game)?,
+        ..default
+    })
+}
+
 pub async fn execute_new_tournament() -> Result<TournamentResult, String> {
     tokio::task::spawn_blocking(move || {
         let num_threads = num_cpus::get();
         let duration = Duration::from_secs(10);
+        let configs = ConfigFactory::with_time_budget(tournament_configs()?, &time_budget()?);
+        // Compiled once, every thread instantiates the same wasm modules
+        let bot_constructors = Arc::new(bot_constructors()?);
         let round_counters: Vec<_> = (0..num_threads)
             .map(|_| Arc::new(AtomicUsize::new(0)))
             .collect();
@@ -39,20 +109,39 @@ pub async fn execute_new_tournament() -> Result<TournamentResult, String> {
         let handles: Vec<_> = round_counters
             .into_iter()
             .map(|counter| {
-                let bot_constructors = available_bots();
+                let bot_constructors = bot_constructors.clone();
+                let configs = configs.clone();
                 thread::spawn(move || {
-                    run_tournament(&bot_constructors, Some(counter), duration, ConfigFactory::generate_tournament_configs())
+                    run_tournament(&bot_constructors, Some(counter), duration, configs)
                 })
             })
             .collect();
 
+        // Join every thread first, one failed thread only skips its own results
         let mut grand_totals = TournamentResult::new();
+        let mut errors = Vec::new();
         for handle in handles {
-            grand_totals.merge_with(&mut handle.join().unwrap());
+            match handle.join() {
+                Ok(mut result) => {
+                    if let Err(err) = grand_totals.merge_with(&mut result) {
+                        errors.push(err.to_string());
+                    }
+                }
+                Err(_) => errors.push("Tournament thread crashed".to_string()),
+            }
         }
 
-        grand_totals
+        if errors.len() == num_threads {
+            return Err(errors.join(", "));
+        }
+        for error in &errors {
+            eprintln!("{error}, its results are skipped");
+        }
+        for failed in &grand_totals.failed_games {
+            eprintln!("Game with seed {} failed: {}", failed.config.seed, failed.error);
+        }
+        Ok(grand_totals)
     })
     .await
-    .map_err(|e| e.to_string())
+    .map_err(|e| e.to_string())?
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+#!/usr/bin/env python3
+"""Example external bot: walks around at random.
+
+Run it in the CLI tournament with:
+    cargo run -p cli -- --external "PyRandom=python3 bots/external/random_bot.py"
+"""
+import json
+import random
+import sys
+
+for line in sys.stdin:
+    message = json.loads(line)
+    if message["type"] == "start":
+        random.seed(message["settings"].get("seed", 0) + message["id"])
+        answer = {"ready": True}
+    else:
+        answer = {"command": random.choice(["Up", "Down", "Left", "Right", "Wait"])}
+    print(json.dumps(answer), flush=True)
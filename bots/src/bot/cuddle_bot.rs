@@ -1,9 +1,10 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 
 use game::bot::bot::Bot;
+use game::bot::observation::Observation;
 use game::coord::Coord;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
 
 pub struct CuddleBot {
@@ -11,6 +12,7 @@ pub struct CuddleBot {
     id: usize,
     debug_info: String,
     map_settings: MapConfig,
+    rng: StdRng,
 }
 
 impl Default for CuddleBot {
@@ -26,10 +28,11 @@ impl CuddleBot {
             id: 0,
             debug_info: "".to_string(),
             map_settings: MapConfig::default(),
+            rng: StdRng::seed_from_u64(0),
         }
     }
 
-    fn safe_moves(&self, map: &Map, me: Coord) -> Vec<Command> {
+    fn safe_moves(&self, observation: &Observation, me: Coord) -> Vec<Command> {
         let mut opts = Vec::new();
 
         for &(command, neighbor_field) in &[
@@ -39,9 +42,7 @@ impl CuddleBot {
             (Command::Right, me.move_right()),
             (Command::Wait, Some(me)),
         ] {
-            let idx =
-                neighbor_field.unwrap().row.get() * map.map_settings.size + neighbor_field.unwrap().col.get();
-            if map.grid.tiles[idx] == ' ' && !self.is_danger(map, neighbor_field.unwrap()) {
+            if observation.is_empty(neighbor_field.unwrap()) && !self.is_danger(observation, neighbor_field.unwrap()) {
                 opts.push(command);
             }
         }
@@ -49,7 +50,7 @@ impl CuddleBot {
         opts
     }
 
-    fn is_a_bot_near(&self, map: &Map, me: Coord) -> bool {
+    fn is_a_bot_near(&self, observation: &Observation, me: Coord) -> bool {
         for &neighbor_field in &[
             (me.move_up()),
             (me.move_down()),
@@ -57,9 +58,7 @@ impl CuddleBot {
             (me.move_right()),
             (Some(me)),
         ] {
-            let idx =
-                neighbor_field.unwrap().row.get() * map.map_settings.size + neighbor_field.unwrap().col.get();
-            if map.grid.tiles[idx] == 'p' {
+            if observation.cell(neighbor_field.unwrap()).as_char() == 'p' {
                 return true;
             }
         }
@@ -67,8 +66,8 @@ impl CuddleBot {
         false
     }
 
-    fn is_danger(&self, map: &Map, locaction: Coord) -> bool {
-        map.bombs.iter().any(|bomb| {
+    fn is_danger(&self, observation: &Observation, locaction: Coord) -> bool {
+        observation.bombs.iter().any(|bomb| {
             let same_row = bomb.position.row.get() == locaction.row.get();
             let same_col = bomb.position.col.get() == locaction.col.get();
             let row_dist =
@@ -81,7 +80,7 @@ impl CuddleBot {
         })
     }
 
-    fn go_to_player(&mut self, map: &Map, me: Coord) -> Vec<Command> {
+    fn go_to_player(&mut self, observation: &Observation, me: Coord) -> Vec<Command> {
         let mut opts = Vec::new();
 
         for &(command, neighbor_field) in &[
@@ -91,9 +90,7 @@ impl CuddleBot {
             (Command::Right, me.move_right()),
             (Command::Wait, Some(me)),
         ] {
-            let idx =
-                neighbor_field.unwrap().row.get() * map.map_settings.size + neighbor_field.unwrap().col.get();
-            if map.grid.tiles[idx] == ' ' && self.is_player_this_direction(map, neighbor_field.unwrap()) {
+            if observation.is_empty(neighbor_field.unwrap()) && self.is_player_this_direction(observation, neighbor_field.unwrap()) {
                 opts.push(command);
             }
         }
@@ -101,8 +98,8 @@ impl CuddleBot {
         opts
     }
 
-    fn is_player_this_direction(&mut self, map: &Map, locaction: Coord) -> bool {
-        map.get_alive_players()
+    fn is_player_this_direction(&mut self, observation: &Observation, locaction: Coord) -> bool {
+        observation.opponents
             .iter()
             .filter(|player| !player.name.contains("CuddleBot"))
             .any(|player| {
@@ -117,8 +114,8 @@ impl CuddleBot {
             })
     }
 
-    fn all_player_names_string(&self, map: &Map) -> String {
-        map.get_alive_players()
+    fn all_player_names_string(&self, observation: &Observation) -> String {
+        observation.opponents
             .iter()
             .filter(|p| p.name != self.name)
             .map(|p| p.name.clone())
@@ -126,8 +123,8 @@ impl CuddleBot {
             .join(", ")
     }
 
-    fn is_other_player_gerhard(&self, map: &Map) -> bool {
-        map.get_alive_players()
+    fn is_other_player_gerhard(&self, observation: &Observation) -> bool {
+        observation.opponents
             .iter()
             .filter(|player| player.name.contains("GerhardBot"))
             .any(|_player| true)
@@ -136,39 +133,39 @@ impl CuddleBot {
 
 impl Bot for CuddleBot {
 
-    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize, rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = settings.clone();
+        self.rng = rng;
         false
     }
 
-    fn get_move(&mut self, map: &Map, me: Coord) -> Command {
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let me = observation.me.position;
         self.debug_info = format!(
             "Players: {}",
-            self.all_player_names_string(map)
+            self.all_player_names_string(observation)
         );
-        if self.is_other_player_gerhard(map) {
+        if self.is_other_player_gerhard(observation) {
             return Command::PlaceBomb;
         }
 
         // Flee
-        let safe = self.safe_moves(map, me);
+        let safe = self.safe_moves(observation, me);
 
         if !safe.is_empty() {
-            let mut random = rand::rng();
-            return *safe.get(random.random_range(0..safe.len())).unwrap();
+            return *safe.get(self.rng.random_range(0..safe.len())).unwrap();
         }
 
         // Hunt
-        let hunt = self.go_to_player(map, me);
+        let hunt = self.go_to_player(observation, me);
         if !hunt.is_empty() {
-            let mut random = rand::rng();
-            return *hunt.get(random.random_range(0..hunt.len())).unwrap();
+            return *hunt.get(self.rng.random_range(0..hunt.len())).unwrap();
         }
 
         // Protect
-        if self.is_a_bot_near(map, me) {
+        if self.is_a_bot_near(observation, me) {
             return Command::PlaceBomb;
         }
 
@@ -177,6 +174,6 @@ impl Bot for CuddleBot {
     }
 
     fn get_debug_info(&self) -> String {
-        return self.debug_info.clone();
+        self.debug_info.clone()
     }
 }
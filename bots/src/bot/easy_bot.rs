@@ -1,7 +1,9 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use game::bot::bot::Bot;
+use game::bot::observation::Observation;
 use game::coord::Coord;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
 
 #[derive(Clone)]
@@ -11,28 +13,28 @@ pub struct EasyBot {
 
     nextmoves: Vec<Command>,
     map_settings: MapConfig,
+    rng: StdRng,
 }
 
 impl Bot for EasyBot {
-    fn get_move(&mut self, map: &Map, player_location: Coord) -> Command {
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let player_location = observation.me.position;
         if !self.nextmoves.is_empty() {
             return self.nextmoves.pop().unwrap();
         }
 
         // Run away if in danger
-        if let Some(runaway) = self.danger_location(map, &player_location) {
+        if let Some(runaway) = self.danger_location(observation, &player_location) {
             return runaway;
         }
 
         // Safe to place a bomb
-        if let Some(moves) = self.safe_to_bomb(map, &player_location) {
+        if let Some(moves) = self.safe_to_bomb(observation, &player_location) {
             self.nextmoves = moves;
             return self.nextmoves.pop().unwrap();
         }
 
         // Random move
-        use rand::Rng;
-        let mut rng = rand::rng();
         let commands = [
             Command::Up,
             Command::Down,
@@ -40,13 +42,14 @@ impl Bot for EasyBot {
             Command::Right,
             Command::Wait,
         ];
-        commands[rng.random_range(0..commands.len())]
+        commands[self.rng.random_range(0..commands.len())]
     }
 
-    fn start_game(&mut self, map_settings: &MapConfig,bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, map_settings: &MapConfig, bot_name: String, bot_id: usize, rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = map_settings.clone();
+        self.rng = rng;
         true
     }
 }
@@ -64,10 +67,11 @@ impl EasyBot {
             id: 0,
             nextmoves: Vec::new(),
             map_settings: MapConfig::default(),
+            rng: StdRng::seed_from_u64(0),
         }
     }
 
-    fn safe_to_bomb(&self, map: &Map, loc: &Coord) -> Option<Vec<Command>> {
+    fn safe_to_bomb(&self, observation: &Observation, loc: &Coord) -> Option<Vec<Command>> {
         let vertical = [Command::Up, Command::Down];
         let horizontal = [Command::Left, Command::Right];
         let options = [
@@ -83,7 +87,7 @@ impl EasyBot {
 
         for (m1, m2) in options {
             if let Some(loc1) = loc.move_command(m1) && let Some(loc2) = loc1.move_command(m2) {
-                if self.get_cell(map, &loc1) == ' ' && self.get_cell(map, &loc2) == ' ' {
+                if observation.is_empty(loc1) && observation.is_empty(loc2) {
                     return Some(vec![
                         Command::Wait,
                         Command::Wait,
@@ -98,22 +102,14 @@ impl EasyBot {
         None
     }
 
-    fn danger_location(&self, map: &Map, player_location: &Coord) -> Option<Command> {
-        for bomb in map.bombs.iter().map(|b| &b.position) {
+    fn danger_location(&self, observation: &Observation, player_location: &Coord) -> Option<Command> {
+        for bomb in observation.bombs.iter().map(|b| &b.position) {
             if in_bomb_range(player_location, bomb, self.map_settings.bomb_radius as u32) {
                 return Some(Command::Left); // temporary naive strategy
             }
         }
         None
     }
-
-    fn get_cell(&self, map: &Map, location: &Coord) -> char {
-        *map
-            .grid
-            .tiles
-            .get(location.row.get() * map.map_settings.size + location.col.get())
-            .unwrap_or(&'W')
-    }
 }
 
 /// Free function to compute distance
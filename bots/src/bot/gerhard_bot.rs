@@ -1,14 +1,16 @@
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use game::bot::bot::Bot;
+use game::bot::observation::Observation;
 use game::coord::Coord;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
 
 pub struct GerhardBot {
     name: String,
     id: usize,
     map_settings: MapConfig,
+    rng: StdRng,
 }
 
 impl Default for GerhardBot {
@@ -23,10 +25,11 @@ impl GerhardBot {
             name: "GerhardBot".to_string(),
             id: 0,
             map_settings: MapConfig::default(),
+            rng: StdRng::seed_from_u64(0),
         }
     }
 
-    fn safe_moves(&self, map: &Map, me: Coord) -> Vec<Command> {
+    fn safe_moves(&self, observation: &Observation, me: Coord) -> Vec<Command> {
         let mut opts = Vec::new();
 
         for &(command, neighbor_field) in &[
@@ -36,9 +39,7 @@ impl GerhardBot {
             (Command::Right, me.move_right()),
             (Command::Wait, Some(me)),
         ] {
-            let idx =
-                neighbor_field.unwrap().row.get() * map.map_settings.size + neighbor_field.unwrap().col.get();
-            if map.grid.tiles[idx] == ' ' && !self.is_danger(map, neighbor_field.unwrap()) {
+            if observation.is_empty(neighbor_field.unwrap()) && !self.is_danger(observation, neighbor_field.unwrap()) {
                 opts.push(command);
             }
         }
@@ -46,7 +47,7 @@ impl GerhardBot {
         opts
     }
 
-    fn is_a_bot_near(&self, map: &Map, me: Coord) -> bool {
+    fn is_a_bot_near(&self, observation: &Observation, me: Coord) -> bool {
         for &neighbor_field in &[
             (me.move_up()),
             (me.move_down()),
@@ -54,9 +55,7 @@ impl GerhardBot {
             (me.move_right()),
             (Some(me)),
         ] {
-            let idx =
-                neighbor_field.unwrap().row.get() * map.map_settings.size + neighbor_field.unwrap().col.get();
-            if map.grid.tiles[idx] == 'p' {
+            if observation.cell(neighbor_field.unwrap()).as_char() == 'p' {
                 return true;
             }
         }
@@ -64,8 +63,8 @@ impl GerhardBot {
         false
     }
 
-    fn is_danger(&self, map: &Map, locaction: Coord) -> bool {
-        map.bombs.iter().any(|bomb| {
+    fn is_danger(&self, observation: &Observation, locaction: Coord) -> bool {
+        observation.bombs.iter().any(|bomb| {
             let same_row = bomb.position.row.get() == locaction.row.get();
             let same_col = bomb.position.col.get() == locaction.col.get();
             let row_dist =
@@ -81,24 +80,25 @@ impl GerhardBot {
 
 impl Bot for GerhardBot {
 
-    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize, rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = settings.clone();
+        self.rng = rng;
         false
     }
 
-    fn get_move(&mut self, map: &Map, me: Coord) -> Command {
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let me = observation.me.position;
         // Flee
-        let safe = self.safe_moves(map, me);
+        let safe = self.safe_moves(observation, me);
 
         if !safe.is_empty() {
-            let mut random = rand::rng();
-            return *safe.get(random.random_range(0..safe.len())).unwrap();
+            return *safe.get(self.rng.random_range(0..safe.len())).unwrap();
         }
 
         // Protect
-        if self.is_a_bot_near(map, me) {
+        if self.is_a_bot_near(observation, me) {
             return Command::PlaceBomb;
         }
 
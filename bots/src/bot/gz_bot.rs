@@ -2,12 +2,12 @@ use crate::bot::gz_logic::helper;
 use crate::bot::gz_logic::tile::Tile;
 use crate::bot::gz_logic::tilemap::TileMap;
 use game::bot::bot::Bot;
+use game::bot::observation::{ObservedBomb, Observation};
 use game::coord::{Col, Coord, Row};
-use game::map::bomb::Bomb;
 use game::map::grid::cell::CellType;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
 use std::collections::{HashMap, VecDeque};
 
 #[derive(Clone)]
@@ -19,6 +19,12 @@ pub struct GzBot {
     map_settings: MapConfig,
 }
 
+impl Default for GzBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl GzBot {
     pub fn new() -> Self {
         GzBot {
@@ -30,12 +36,12 @@ impl GzBot {
         }
     }
 
-    fn generate_tile_map(&self, map: &Map, player_position: Coord) -> TileMap {
+    fn generate_tile_map(&self, observation: &Observation, player_position: Coord) -> TileMap {
         let mut tile_map: Vec<Vec<Tile>> = Vec::new();
 
-        for (index, char) in map.grid.tiles.iter().enumerate() {
+        for (index, cell) in observation.grid.iter().enumerate() {
             let coord: Coord = self.get_coord_from_index(index);
-            let mut cell_type = helper::get_cell_type(char);
+            let mut cell_type = helper::get_cell_type(*cell);
             if coord == player_position {
                 cell_type = CellType::Empty;
             }
@@ -43,7 +49,7 @@ impl GzBot {
                 coord,
                 cell_type,
                 visited: false,
-                safe: self.is_tile_safe(map, coord),
+                safe: self.is_tile_safe(observation, coord),
             };
 
             while tile_map.len() <= coord.row.get() {
@@ -75,7 +81,7 @@ impl GzBot {
         }
         score -= distance as i32;
 
-        return score;
+        score
     }
 
     fn reachable_tiles_with_scores(
@@ -98,7 +104,7 @@ impl GzBot {
         while let Some((current_coord, dist)) = queue.pop_front() {
             let tile = tile_map.get(current_coord).unwrap();
 
-            let score = self.score_tile(&tile_map, tile, dist);
+            let score = self.score_tile(tile_map, tile, dist);
             scores.insert(current_coord, (score, dist));
 
             for coord in helper::get_neighbour_coords(tile.coord) {
@@ -115,14 +121,11 @@ impl GzBot {
     }
 
     fn is_tile_walkable(&self, tile: &Tile) -> bool {
-        match tile.cell_type {
-            CellType::Empty => true,
-            _ => false,
-        }
+        matches!(tile.cell_type, CellType::Empty)
     }
 
-    fn is_tile_safe(&self, map: &Map, coord: Coord) -> bool {
-        for bomb in map.bombs.iter() {
+    fn is_tile_safe(&self, observation: &Observation, coord: Coord) -> bool {
+        for bomb in observation.bombs.iter() {
             if helper::is_tile_in_bomb_range(coord, bomb.position, self.map_settings.bomb_radius) {
                 return false;
             }
@@ -133,19 +136,19 @@ impl GzBot {
 
     #[inline(always)]
     fn get_coord_from_index(&self, index: usize) -> Coord {
-        let row = index / self.map_settings.size;
-        let col = index % self.map_settings.size;
+        let row = index / self.map_settings.width();
+        let col = index % self.map_settings.width();
 
-        return Coord {
+        Coord {
             col: Col::new(col),
             row: Row::new(row),
-        };
+        }
     }
 
     fn try_find_path<'a>(
         &mut self,
         tile_map: &'a TileMap,
-        bombs: &Vec<Bomb>,
+        bombs: &[ObservedBomb],
         player_location: Coord,
         target_location: Coord,
     ) -> Option<(i32, Vec<&'a Tile>)> {
@@ -161,11 +164,11 @@ impl GzBot {
             return possible_path;
         }
 
-        return None;
+        None
     }
 
     fn get_flee_location(&mut self, tile_map: &TileMap, current_tile: &Tile) -> Option<Coord> {
-        let possible_safe_tile = tile_map.nearest_safe_tile(&current_tile);
+        let possible_safe_tile = tile_map.nearest_safe_tile(current_tile);
         if let Some(safe_tile) = possible_safe_tile {
             return Some(safe_tile.coord);
         }
@@ -175,14 +178,15 @@ impl GzBot {
 }
 
 impl Bot for GzBot {
-    fn start_game(&mut self, settings: &MapConfig, _bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, _bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
         self.id = bot_id;
         self.map_settings = settings.clone();
         true
     }
 
-    fn get_move(&mut self, map: &Map, player_location: Coord) -> Command {
-        let mut tile_map = self.generate_tile_map(map, player_location);
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let player_location = observation.me.position;
+        let mut tile_map = self.generate_tile_map(observation, player_location);
         if let Some(current_tile) = tile_map.get(player_location) {
             if !current_tile.safe {
                 self.fleeing = true;
@@ -212,7 +216,7 @@ impl Bot for GzBot {
                         if let Some((_cost, escape_path)) = tile_map.dijkstra(
                             current_tile,
                             escape_tile,
-                            &map.bombs,
+                            &observation.bombs,
                             self.map_settings.bomb_radius,
                         ) {
                             if escape_path.len() <= 4 {
@@ -232,21 +236,22 @@ impl Bot for GzBot {
             .map(|(coord, (score, dist))| (coord, score, dist))
             .collect();
 
+        // Ties are broken on the coordinate, the HashMap order differs between runs
         scored_moves.sort_by(|a, b| {
             if a.1 != b.1 {
                 b.1.cmp(&a.1)
             } else {
-                a.2.cmp(&b.2)
+                a.2.cmp(&b.2).then(a.0.cmp(&b.0))
             }
         });
 
-        for (target, score, dist) in scored_moves {
+        for (target, _score, _dist) in scored_moves {
             if target == player_location {
                 continue;
             }
 
-            if let Some(path) = self.try_find_path(&tile_map, &map.bombs, player_location, target) {
-                if path.1.len() > 0 {
+            if let Some(path) = self.try_find_path(&tile_map, &observation.bombs, player_location, target) {
+                if !path.1.is_empty() {
 
                     return helper::get_command_to_move_to_coord(player_location, path.1[0].coord);
                 }
@@ -269,6 +274,6 @@ impl Bot for GzBot {
     }
 
     fn get_debug_info(&self) -> String {
-        return self.debug_info.clone();
+        self.debug_info.clone()
     }
 }
@@ -1,7 +1,7 @@
 use std::cmp::Reverse;
 use std::collections::{BinaryHeap, HashMap};
 use game::coord::Coord;
-use game::map::bomb::Bomb;
+use game::bot::observation::ObservedBomb;
 use game::map::grid::cell::CellType;
 use crate::bot::gz_logic::helper;
 use crate::bot::gz_logic::tile::Tile;
@@ -24,7 +24,7 @@ impl TileMap {
             .and_then(|row| row.get(coord.col.get()))
     }
 
-    pub(crate) fn dijkstra<'a>(&'a self, start: &Tile, goal: &'a Tile, bombs: &Vec<Bomb>, bomb_radius: usize) -> Option<(i32, Vec<&'a Tile>)> {
+    pub(crate) fn dijkstra<'a>(&'a self, start: &Tile, goal: &'a Tile, bombs: &[ObservedBomb], bomb_radius: usize) -> Option<(i32, Vec<&'a Tile>)> {
         let mut dist: HashMap<Coord, i32> = HashMap::new();
         let mut came_from: HashMap<Coord, Coord> = HashMap::new();
         let mut heap = BinaryHeap::new();
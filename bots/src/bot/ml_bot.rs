@@ -1,11 +1,12 @@
 use std::collections::VecDeque;
 
 use game::bot::bot::Bot;
+use game::bot::observation::Observation;
 use game::coord::Coord;
 use game::map::enums::command::Command;
-use game::map::map::Map;
+use game::map::grid::cell::CellType;
 use game::map::structs::map_config::MapConfig;
-use game::map::shrink::calculate_shrink_location;
+use rand::rngs::StdRng;
 
 #[derive(Clone)]
 struct Node {
@@ -22,8 +23,13 @@ pub struct MlBot {
     pub name: String,
     pub id: usize,
     map_settings: MapConfig,
-    turn: usize,
-    next_shrink_location: Option<Coord>,
+    next_shrink_locations: Vec<Coord>,
+}
+
+impl Default for MlBot {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl MlBot {
@@ -32,24 +38,23 @@ impl MlBot {
             name: "MartijnBot".to_string(),
             id: 0,
             map_settings: MapConfig::default(),
-            turn: 0,
-            next_shrink_location: None,
+            next_shrink_locations: Vec::new(),
         }
     }
 
-    fn propagate_and_normalize(&self, map: &Map, mut heatmap: Vec<f32>) -> Vec<f32> {
+    fn propagate_and_normalize(&self, observation: &Observation, mut heatmap: Vec<f32>) -> Vec<f32> {
         for _ in 0..5 {
-            heatmap = self.propagate_heatmap(map, &heatmap);
+            heatmap = self.propagate_heatmap(observation, &heatmap);
         }
         self.normalize_vec(&mut heatmap);
 
         heatmap
     }
 
-    fn create_enemy_heatmap(&self, map: &Map) -> Vec<f32> {
-        let mut heatmap = vec![0.01; self.map_settings.size * self.map_settings.size];
+    fn create_enemy_heatmap(&self, observation: &Observation) -> Vec<f32> {
+        let mut heatmap = vec![0.01; self.map_settings.width() * self.map_settings.height()];
 
-        map.get_alive_players()
+        observation.opponents
             .iter()
             .filter(|p| !p.name.contains(&self.name))
             .for_each(|p| {
@@ -58,14 +63,14 @@ impl MlBot {
 
         heatmap
     }
-    fn create_breakable_heatmap(&self, map: &Map) -> Vec<f32> {
+    fn create_breakable_heatmap(&self, observation: &Observation) -> Vec<f32> {
         let mut heatmap = self.empty_heatmap();
 
-        for row in 0..map.map_settings.size {
-            for col in 0..map.map_settings.size {
+        for row in 0..observation.height {
+            for col in 0..observation.width {
                 let idx = self.idx(row, col);
-                let cell = self.get_map_cell(row, col, map);
-                if cell == '.' {
+                let cell = self.get_map_cell(row, col, observation);
+                if cell == CellType::Destroyable {
                     heatmap[idx] = 1.0;
                 }
             }
@@ -74,10 +79,10 @@ impl MlBot {
         heatmap
     }
 
-    fn create_blast_heatmap(&self, map: &Map) -> Vec<f32> {
+    fn create_blast_heatmap(&self, observation: &Observation) -> Vec<f32> {
         let mut heatmap = self.empty_heatmap();
 
-        for bomb in &map.bombs {
+        for bomb in &observation.bombs {
             let bomb_heat = 1.0 / (bomb.timer as f32);
 
             let bomb_row = bomb.position.row.get();
@@ -90,7 +95,7 @@ impl MlBot {
                     let new_col = (bomb_col as isize + delta_col * distance as isize) as usize;
 
                     if !self.out_of_bounds(new_row, new_col)
-                        && !self.is_wall(map, new_row, new_col)
+                        && !self.is_wall(observation, new_row, new_col)
                     {
                         let idx = self.idx(new_row, new_col);
                         heatmap[idx] = bomb_heat;
@@ -104,11 +109,11 @@ impl MlBot {
         heatmap
     }
 
-    fn propagate_heatmap(&self, map: &Map, heatmap: &Vec<f32>) -> Vec<f32> {
-        let mut propagated_heatmap = heatmap.clone();
+    fn propagate_heatmap(&self, observation: &Observation, heatmap: &[f32]) -> Vec<f32> {
+        let mut propagated_heatmap = heatmap.to_vec();
 
-        for row in 0..map.map_settings.size {
-            for col in 0..map.map_settings.size {
+        for row in 0..observation.height {
+            for col in 0..observation.width {
                 let index = self.idx(row, col);
                 let original_value = heatmap[index];
                 if original_value > 0.0 {
@@ -117,7 +122,7 @@ impl MlBot {
                         let new_col = (col as isize + delta_col) as usize;
 
                         if !self.out_of_bounds(new_row, new_col)
-                            && !self.is_wall(map, new_row, new_col)
+                            && !self.is_wall(observation, new_row, new_col)
                         {
                             let new_index = self.idx(new_row, new_col);
                             propagated_heatmap[new_index] += original_value * 0.25;
@@ -130,7 +135,7 @@ impl MlBot {
 
         propagated_heatmap
     }
-    fn normalize_vec(&self, heatmap: &mut Vec<f32>) {
+    fn normalize_vec(&self, heatmap: &mut [f32]) {
         if let Some(&max_value) = heatmap.iter().max_by(|a, b| a.partial_cmp(b).unwrap()) {
             if max_value > 0.0 {
                 for value in heatmap.iter_mut() {
@@ -142,10 +147,10 @@ impl MlBot {
 
     fn find_escape_path(
         &self,
-        map: &Map,
+        observation: &Observation,
         start_row: usize,
         start_col: usize,
-        bomb_heatmap: &Vec<f32>,
+        bomb_heatmap: &[f32],
     ) -> Option<(Command, usize)> {
         let mut visited = vec![false; bomb_heatmap.len()];
         let mut queue = VecDeque::new();
@@ -190,7 +195,7 @@ impl MlBot {
                     continue;
                 }
 
-                if !self.is_clear(map, nr, nc) {
+                if !self.is_clear(observation, nr, nc) {
                     continue;
                 }
 
@@ -215,8 +220,8 @@ impl MlBot {
 
 
 
-    fn can_safely_place_bomb(&self, map: &Map, pos_row: usize, pos_col: usize, current_bomb_heatmap: &Vec<f32>) -> bool {
-        let mut simulated_heatmap = current_bomb_heatmap.clone();
+    fn can_safely_place_bomb(&self, observation: &Observation, pos_row: usize, pos_col: usize, current_bomb_heatmap: &[f32]) -> bool {
+        let mut simulated_heatmap = current_bomb_heatmap.to_vec();
         let bomb_heat = 1.0;
 
         simulated_heatmap[self.idx(pos_row, pos_col)] = bomb_heat;
@@ -231,24 +236,24 @@ impl MlBot {
                 let nc = nc_isize as usize;
                 if self.out_of_bounds(nr, nc) { break; }
 
-                let cell = self.get_map_cell(nr, nc, map);
-                if cell == 'W' { break; }
+                let cell = self.get_map_cell(nr, nc, observation);
+                if cell == CellType::Wall { break; }
 
                 simulated_heatmap[self.idx(nr, nc)] = bomb_heat;
             }
         }
-        self.find_escape_path(map, pos_row, pos_col, &simulated_heatmap).is_some()
+        self.find_escape_path(observation, pos_row, pos_col, &simulated_heatmap).is_some()
     }
 
 
-    fn decide_move(&mut self, map: &Map, player_location: Coord) -> Command {
-        let enemy_heatmap = self.propagate_and_normalize(map, self.create_enemy_heatmap(map));
-        let bomb_heatmap = self.create_blast_heatmap(map);
-        let breakable_heatmap = self.propagate_and_normalize(map, self.create_breakable_heatmap(map));
+    fn decide_move(&mut self, observation: &Observation, player_location: Coord) -> Command {
+        let enemy_heatmap = self.propagate_and_normalize(observation, self.create_enemy_heatmap(observation));
+        let bomb_heatmap = self.create_blast_heatmap(observation);
+        let breakable_heatmap = self.propagate_and_normalize(observation, self.create_breakable_heatmap(observation));
         let player_row = player_location.row.get();
         let player_col = player_location.col.get();
         let current_index = self.idx(player_row, player_col);
-        let escape_path: Option<(Command,usize)> = self.find_escape_path(map, player_row, player_col, &bomb_heatmap);
+        let escape_path: Option<(Command,usize)> = self.find_escape_path(observation, player_row, player_col, &bomb_heatmap);
         if bomb_heatmap[current_index] > 0.0 {
             return if let Some((direction, _steps)) = escape_path {
                 direction
@@ -270,7 +275,7 @@ impl MlBot {
             let new_row = (player_row as isize + delta_row) as usize;
             let new_col = (player_col as isize + delta_col) as usize;
             let is_wait = delta_row == 0 && delta_col == 0;
-            if  !is_wait && !self.is_clear(map, new_row, new_col) {
+            if  !is_wait && !self.is_clear(observation, new_row, new_col) {
                 continue;
             }
 
@@ -282,7 +287,7 @@ impl MlBot {
             let breakable_heat = breakable_heatmap[idx];
 
             let escape_score = if let Some((_dir, steps)) = self.find_escape_path(
-                map,
+                observation,
                 new_row,
                 new_col,
                 &bomb_heatmap,
@@ -317,7 +322,7 @@ impl MlBot {
             let breakable_nearby =  breakable_heatmap[current_index] > 0.3;
 
             if enemy_nearby || breakable_nearby {
-                if self.can_safely_place_bomb(map, player_row, player_col, &bomb_heatmap) {
+                if self.can_safely_place_bomb(observation, player_row, player_col, &bomb_heatmap) {
                     return Command::PlaceBomb;
                 }
             }
@@ -328,29 +333,26 @@ impl MlBot {
 
     #[inline(always)]
     fn empty_heatmap(&self) -> Vec<f32> {
-        vec![0.0; self.map_settings.size * self.map_settings.size]
+        vec![0.0; self.map_settings.width() * self.map_settings.height()]
     }
 
     #[inline(always)]
-    fn is_wall(&self, map: &Map, row: usize, col: usize) -> bool {
-        let cell = self.get_map_cell(row, col, map);
-        cell == 'W' ||  cell == '.'
+    fn is_wall(&self, observation: &Observation, row: usize, col: usize) -> bool {
+        let cell = self.get_map_cell(row, col, observation);
+        cell == CellType::Wall ||  cell == CellType::Destroyable
     }
 
     #[inline(always)]
-    fn is_clear(&self, map: &Map, row: usize, col: usize) -> bool {
-        self.get_map_cell(row, col, map) == ' ' && (self.next_shrink_location.is_none()  || (
-            self.next_shrink_location.is_some()
-            && self.next_shrink_location.unwrap().col.get() != col
-            && self.next_shrink_location.unwrap().row.get() != row))
+    fn is_clear(&self, observation: &Observation, row: usize, col: usize) -> bool {
+        self.get_map_cell(row, col, observation) == CellType::Empty && !self.next_shrink_locations.contains(&Coord::from(col, row))
     }
 
     #[inline(always)]
-    fn get_map_cell(&self, row: usize, col: usize, map: &Map) -> char {
-        self.get_grid_value(&map.grid.tiles, row, col)
+    fn get_map_cell(&self, row: usize, col: usize, observation: &Observation) -> CellType {
+        self.get_grid_value(&observation.grid, row, col)
     }
     #[inline(always)]
-    fn get_grid_value<T: Copy>(&self, grid: &Vec<T>, row: usize, col: usize) -> T {
+    fn get_grid_value<T: Copy>(&self, grid: &[T], row: usize, col: usize) -> T {
         *grid
             .get(self.idx(row, col))
             .expect("Out of bounds")
@@ -358,36 +360,26 @@ impl MlBot {
 
     #[inline(always)]
     fn idx(&self, row: usize, col: usize) -> usize {
-        row * self.map_settings.size + col
+        row * self.map_settings.width() + col
     }
     #[inline(always)]
     fn out_of_bounds(&self, row: usize, col: usize) -> bool {
-        row >= self.map_settings.size || col >= self.map_settings.size
+        row >= self.map_settings.height() || col >= self.map_settings.width()
     }
 }
 
 impl Bot for MlBot {
 
-    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = settings.clone();
         true
     }
 
-    fn get_move(&mut self, map: &Map, _player_location: Coord) -> Command {
-        if map.map_settings.endgame <= self.turn {
-            self.next_shrink_location = None;
-            if let Some(shrink_location) = calculate_shrink_location(
-                self.turn - map.map_settings.endgame,
-                map.map_settings.size,
-            ) {
-                self.next_shrink_location = Some(shrink_location);
-            }
-        }
-        self.turn = self.turn + 1;
-        let next_move = self.decide_move(map, _player_location);
-        next_move
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        self.next_shrink_locations = observation.shrink_this_turn();
+        self.decide_move(observation, observation.me.position)
     }
 
 }
@@ -3,11 +3,12 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 use game::bot::bot::Bot;
+use game::bot::observation::Observation;
 use game::coord::Coord;
 use game::map::enums::command::Command;
-use game::map::shrink::calculate_shrink_location;
-use game::map::map::Map;
+use game::map::grid::cell::CellType;
 use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
 use rand::Rng;
 use rand_distr::{Distribution, StandardNormal};
 
@@ -180,8 +181,7 @@ pub struct NeuralBot {
     name: String,
     id: usize,
     map_settings: MapConfig,
-    turn: usize,
-    next_shrink_location: Option<Coord>,
+    next_shrink_locations: Vec<Coord>,
     network: NeuralNetwork,
     last_debug: String,
     command_list: Vec<Command>,
@@ -190,6 +190,12 @@ pub struct NeuralBot {
     initialized: bool,
 }
 
+impl Default for NeuralBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl NeuralBot {
     pub fn new() -> Self {
         Self::with_weights(Arc::new(NeuralWeights::default()), "NeuralNemesis".to_string())
@@ -281,8 +287,7 @@ impl NeuralBot {
             name: label,
             id: 0,
             map_settings: MapConfig::default(),
-            turn: 0,
-            next_shrink_location: None,
+            next_shrink_locations: Vec::new(),
             network: NeuralNetwork::new(weights),
             last_debug: String::new(),
             command_list: vec![],
@@ -296,17 +301,17 @@ impl NeuralBot {
         self.network.weights()
     }
 
-    fn decide_move(&mut self, map: &Map, player_location: Coord) -> Command {
-        let enemy_heatmap = self.propagate_and_normalize(map, self.create_enemy_heatmap(map));
-        let breakable_heatmap = self.propagate_and_normalize(map, self.create_breakable_heatmap(map));
-    let danger_heatmap = self.create_danger_heatmap(map);
+    fn decide_move(&mut self, observation: &Observation, player_location: Coord) -> Command {
+        let enemy_heatmap = self.propagate_and_normalize(observation, self.create_enemy_heatmap(observation));
+        let breakable_heatmap = self.propagate_and_normalize(observation, self.create_breakable_heatmap(observation));
+    let danger_heatmap = self.create_danger_heatmap(observation);
 
         let player_row = player_location.row.get();
         let player_col = player_location.col.get();
         let current_idx = self.idx(player_row, player_col);
 
         if danger_heatmap[current_idx] > 0.0 {
-            if let Some((escape_dir, _)) = self.find_escape_path(map, player_row, player_col, &danger_heatmap) {
+            if let Some((escape_dir, _)) = self.find_escape_path(observation, player_row, player_col, &danger_heatmap) {
                 self.last_debug = format!("forced_escape:{escape_dir:?}");
                 return escape_dir;
             }
@@ -318,7 +323,7 @@ impl NeuralBot {
 
         for action in ACTIONS.iter().copied() {
             if let Some((score, log_entry)) = self.evaluate_action(
-                map,
+                observation,
                 player_row,
                 player_col,
                 action,
@@ -345,40 +350,40 @@ impl NeuralBot {
 
     fn evaluate_action(
         &self,
-        map: &Map,
+        observation: &Observation,
         row: usize,
         col: usize,
         action: Command,
-        enemy_heatmap: &Vec<f32>,
-        breakable_heatmap: &Vec<f32>,
-        danger_heatmap: &Vec<f32>,
+        enemy_heatmap: &[f32],
+        breakable_heatmap: &[f32],
+        danger_heatmap: &[f32],
     ) -> Option<(f32, String)> {
-        if matches!(action, Command::PlaceBomb) && self.get_map_cell(row, col, map) == 'B' {
+        if matches!(action, Command::PlaceBomb) && self.get_map_cell(row, col, observation) == CellType::Bomb {
             return None;
         }
 
         let (target_row, target_col) = self.apply_action(row, col, action)?;
 
-        if !matches!(action, Command::Wait | Command::PlaceBomb) && !self.is_clear(map, target_row, target_col) {
+        if !matches!(action, Command::Wait | Command::PlaceBomb) && !self.is_clear(observation, target_row, target_col) {
             return None;
         }
 
         let (local_danger, escape_steps) = if matches!(action, Command::PlaceBomb) {
-            let simulated = self.simulate_bomb(map, danger_heatmap, row, col);
-            match self.find_escape_path(map, row, col, &simulated) {
+            let simulated = self.simulate_bomb(observation, danger_heatmap, row, col);
+            match self.find_escape_path(observation, row, col, &simulated) {
                 Some((_, steps)) => (simulated, Some(steps)),
                 None => return None,
             }
         } else {
             (
-                danger_heatmap.clone(),
-                self.find_escape_path(map, target_row, target_col, danger_heatmap)
+                danger_heatmap.to_vec(),
+                self.find_escape_path(observation, target_row, target_col, danger_heatmap)
                     .map(|(_, steps)| steps),
             )
         };
 
         let features = self.build_features(
-            map,
+            observation,
             row,
             col,
             target_row,
@@ -400,15 +405,15 @@ impl NeuralBot {
 
     fn build_features(
         &self,
-        map: &Map,
+        observation: &Observation,
         row: usize,
         col: usize,
         target_row: usize,
         target_col: usize,
         action: Command,
-        enemy_heatmap: &Vec<f32>,
-        breakable_heatmap: &Vec<f32>,
-        danger_heatmap: &Vec<f32>,
+        enemy_heatmap: &[f32],
+        breakable_heatmap: &[f32],
+        danger_heatmap: &[f32],
         escape_steps: Option<usize>,
     ) -> [f32; INPUT_SIZE] {
         let idx_current = self.idx(row, col);
@@ -427,11 +432,11 @@ impl NeuralBot {
         let breakable_pressure = breakable_heatmap[idx_target];
         let center_bias = self.center_bias(target_row, target_col);
         let kill_potential = if matches!(action, Command::PlaceBomb) {
-            self.bomb_kill_score(map, row, col)
+            self.bomb_kill_score(observation, row, col)
         } else {
-            self.adjacent_enemy_score(map, target_row, target_col)
+            self.adjacent_enemy_score(observation, target_row, target_col)
         };
-        let mobility_score = self.safe_neighbor_ratio(map, target_row, target_col, danger_heatmap);
+        let mobility_score = self.safe_neighbor_ratio(observation, target_row, target_col, danger_heatmap);
 
         let mut encoded = [0.0_f32; INPUT_SIZE];
         encoded[0] = danger_here;
@@ -449,8 +454,8 @@ impl NeuralBot {
         encoded
     }
 
-    fn simulate_bomb(&self, map: &Map, danger_heatmap: &Vec<f32>, row: usize, col: usize) -> Vec<f32> {
-        let mut simulated = danger_heatmap.clone();
+    fn simulate_bomb(&self, observation: &Observation, danger_heatmap: &[f32], row: usize, col: usize) -> Vec<f32> {
+        let mut simulated = danger_heatmap.to_vec();
         let idx = self.idx(row, col);
         simulated[idx] = 1.0;
 
@@ -469,10 +474,10 @@ impl NeuralBot {
                     break;
                 }
 
-                let cell = self.get_map_cell(nr, nc, map);
+                let cell = self.get_map_cell(nr, nc, observation);
                 simulated[self.idx(nr, nc)] = 1.0;
 
-                if cell == 'W' || cell == '.' {
+                if cell == CellType::Wall || cell == CellType::Destroyable {
                     break;
                 }
             }
@@ -481,18 +486,18 @@ impl NeuralBot {
         simulated
     }
 
-    fn propagate_and_normalize(&self, map: &Map, mut heatmap: Vec<f32>) -> Vec<f32> {
+    fn propagate_and_normalize(&self, observation: &Observation, mut heatmap: Vec<f32>) -> Vec<f32> {
         for _ in 0..4 {
-            heatmap = self.propagate_heatmap(map, &heatmap);
+            heatmap = self.propagate_heatmap(observation, &heatmap);
         }
         self.normalize_vec(&mut heatmap);
         heatmap
     }
 
-    fn create_enemy_heatmap(&self, map: &Map) -> Vec<f32> {
-        let mut heatmap = vec![0.01; self.map_settings.size * self.map_settings.size];
+    fn create_enemy_heatmap(&self, observation: &Observation) -> Vec<f32> {
+        let mut heatmap = vec![0.01; self.map_settings.width() * self.map_settings.height()];
 
-        map.get_alive_players()
+        observation.opponents
             .iter()
             .filter(|p| p.id != self.id)
             .for_each(|p| {
@@ -502,12 +507,12 @@ impl NeuralBot {
         heatmap
     }
 
-    fn create_breakable_heatmap(&self, map: &Map) -> Vec<f32> {
+    fn create_breakable_heatmap(&self, observation: &Observation) -> Vec<f32> {
         let mut heatmap = self.empty_heatmap();
-        for row in 0..self.map_settings.size {
-            for col in 0..self.map_settings.size {
+        for row in 0..self.map_settings.height() {
+            for col in 0..self.map_settings.width() {
                 let idx = self.idx(row, col);
-                if self.get_map_cell(row, col, map) == '.' {
+                if self.get_map_cell(row, col, observation) == CellType::Destroyable {
                     heatmap[idx] = 1.0;
                 }
             }
@@ -515,9 +520,9 @@ impl NeuralBot {
         heatmap
     }
 
-    fn create_danger_heatmap(&self, map: &Map) -> Vec<f32> {
+    fn create_danger_heatmap(&self, observation: &Observation) -> Vec<f32> {
         let mut heatmap = self.empty_heatmap();
-        for bomb in &map.bombs {
+        for bomb in &observation.bombs {
             let intensity = 1.0 / ((bomb.timer.max(1)) as f32);
             let row = bomb.position.row.get();
             let col = bomb.position.col.get();
@@ -538,11 +543,11 @@ impl NeuralBot {
                         break;
                     }
 
-                    let cell = self.get_map_cell(nr, nc, map);
+                    let cell = self.get_map_cell(nr, nc, observation);
                     let idx = self.idx(nr, nc);
                     heatmap[idx] = heatmap[idx].max(intensity);
 
-                    if cell == 'W' || cell == '.' {
+                    if cell == CellType::Wall || cell == CellType::Destroyable {
                         break;
                     }
                 }
@@ -553,10 +558,10 @@ impl NeuralBot {
 
     fn find_escape_path(
         &self,
-        map: &Map,
+        observation: &Observation,
         start_row: usize,
         start_col: usize,
-        danger_heatmap: &Vec<f32>,
+        danger_heatmap: &[f32],
     ) -> Option<(Command, usize)> {
         let mut visited = vec![false; danger_heatmap.len()];
         let mut queue = VecDeque::new();
@@ -601,7 +606,7 @@ impl NeuralBot {
                     continue;
                 }
 
-                if !self.is_clear(map, nr, nc) {
+                if !self.is_clear(observation, nr, nc) {
                     continue;
                 }
 
@@ -623,11 +628,11 @@ impl NeuralBot {
         None
     }
 
-    fn propagate_heatmap(&self, map: &Map, heatmap: &Vec<f32>) -> Vec<f32> {
-        let mut propagated = heatmap.clone();
+    fn propagate_heatmap(&self, observation: &Observation, heatmap: &[f32]) -> Vec<f32> {
+        let mut propagated = heatmap.to_vec();
 
-        for row in 0..self.map_settings.size {
-            for col in 0..self.map_settings.size {
+        for row in 0..self.map_settings.height() {
+            for col in 0..self.map_settings.width() {
                 let index = self.idx(row, col);
                 let original = heatmap[index];
                 if original <= 0.0 {
@@ -635,14 +640,14 @@ impl NeuralBot {
                 }
 
                 for (dr, dc) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
-                    let nr = (row as isize + dr) as isize;
-                    let nc = (col as isize + dc) as isize;
+                    let nr = row as isize + dr ;
+                    let nc = col as isize + dc ;
                     if nr < 0 || nc < 0 {
                         continue;
                     }
                     let nr = nr as usize;
                     let nc = nc as usize;
-                    if self.out_of_bounds(nr, nc) || self.is_wall(map, nr, nc) {
+                    if self.out_of_bounds(nr, nc) || self.is_wall(observation, nr, nc) {
                         continue;
                     }
                     let idx = self.idx(nr, nc);
@@ -655,7 +660,7 @@ impl NeuralBot {
         propagated
     }
 
-    fn normalize_vec(&self, heatmap: &mut Vec<f32>) {
+    fn normalize_vec(&self, heatmap: &mut [f32]) {
         if let Some(&max_value) = heatmap.iter().max_by(|a, b| a.partial_cmp(b).unwrap()) {
             if max_value > 0.0 {
                 for value in heatmap.iter_mut() {
@@ -665,12 +670,12 @@ impl NeuralBot {
         }
     }
 
-    fn safe_neighbor_ratio(&self, map: &Map, row: usize, col: usize, danger_heatmap: &Vec<f32>) -> f32 {
+    fn safe_neighbor_ratio(&self, observation: &Observation, row: usize, col: usize, danger_heatmap: &[f32]) -> f32 {
         let mut total = 0;
         let mut safe = 0;
         for action in [Command::Up, Command::Down, Command::Left, Command::Right] {
             if let Some((nr, nc)) = self.apply_action(row, col, action) {
-                if self.is_clear(map, nr, nc) {
+                if self.is_clear(observation, nr, nc) {
                     total += 1;
                     if danger_heatmap[self.idx(nr, nc)] == 0.0 {
                         safe += 1;
@@ -686,16 +691,17 @@ impl NeuralBot {
     }
 
     fn center_bias(&self, row: usize, col: usize) -> f32 {
-        let center = (self.map_settings.size as f32 - 1.0) / 2.0;
-        let dist_row = (row as f32 - center).abs();
-        let dist_col = (col as f32 - center).abs();
-        let max_dist = center * 2.0;
+        let center_row = (self.map_settings.height() as f32 - 1.0) / 2.0;
+        let center_col = (self.map_settings.width() as f32 - 1.0) / 2.0;
+        let dist_row = (row as f32 - center_row).abs();
+        let dist_col = (col as f32 - center_col).abs();
+        let max_dist = center_row + center_col;
         1.0 - ((dist_row + dist_col) / max_dist)
     }
 
-    fn bomb_kill_score(&self, map: &Map, row: usize, col: usize) -> f32 {
+    fn bomb_kill_score(&self, observation: &Observation, row: usize, col: usize) -> f32 {
     let mut best: f32 = 0.0;
-        for player in map.get_alive_players() {
+        for player in &observation.opponents {
             if player.id == self.id {
                 continue;
             }
@@ -703,13 +709,13 @@ impl NeuralBot {
             let pcol = player.position.col.get();
             if prow == row {
                 let dist = pcol.abs_diff(col);
-                if dist as usize <= self.map_settings.bomb_radius && self.line_clear(map, row, col, prow, pcol) {
+                if dist <= self.map_settings.bomb_radius && self.line_clear(observation, row, col, prow, pcol) {
                     let score = 1.0 - (dist as f32 / (self.map_settings.bomb_radius as f32 + 1.0));
                     best = best.max(score);
                 }
             } else if pcol == col {
                 let dist = prow.abs_diff(row);
-                if dist as usize <= self.map_settings.bomb_radius && self.line_clear(map, row, col, prow, pcol) {
+                if dist <= self.map_settings.bomb_radius && self.line_clear(observation, row, col, prow, pcol) {
                     let score = 1.0 - (dist as f32 / (self.map_settings.bomb_radius as f32 + 1.0));
                     best = best.max(score);
                 }
@@ -718,9 +724,9 @@ impl NeuralBot {
         best
     }
 
-    fn adjacent_enemy_score(&self, map: &Map, row: usize, col: usize) -> f32 {
+    fn adjacent_enemy_score(&self, observation: &Observation, row: usize, col: usize) -> f32 {
     let mut best: f32 = 0.0;
-        for player in map.get_alive_players() {
+        for player in &observation.opponents {
             if player.id == self.id {
                 continue;
             }
@@ -733,20 +739,20 @@ impl NeuralBot {
         best
     }
 
-    fn line_clear(&self, map: &Map, row: usize, col: usize, target_row: usize, target_col: usize) -> bool {
+    fn line_clear(&self, observation: &Observation, row: usize, col: usize, target_row: usize, target_col: usize) -> bool {
         if row == target_row {
             let (start, end) = if col < target_col { (col + 1, target_col) } else { (target_col + 1, col) };
             for c in start..end {
-                let cell = self.get_map_cell(row, c, map);
-                if cell == 'W' || cell == '.' {
+                let cell = self.get_map_cell(row, c, observation);
+                if cell == CellType::Wall || cell == CellType::Destroyable {
                     return false;
                 }
             }
         } else if col == target_col {
             let (start, end) = if row < target_row { (row + 1, target_row) } else { (target_row + 1, row) };
             for r in start..end {
-                let cell = self.get_map_cell(r, col, map);
-                if cell == 'W' || cell == '.' {
+                let cell = self.get_map_cell(r, col, observation);
+                if cell == CellType::Wall || cell == CellType::Destroyable {
                     return false;
                 }
             }
@@ -763,6 +769,8 @@ impl NeuralBot {
             Command::Right => 3,
             Command::Wait => 4,
             Command::PlaceBomb => 5,
+            // The network only picks the classic actions, anything else stays put.
+            Command::KickBomb(_) | Command::Detonate => 4,
         };
         encoding[index] = 1.0;
         encoding
@@ -772,7 +780,7 @@ impl NeuralBot {
         match action {
             Command::Up => row.checked_sub(1).map(|r| (r, col)),
             Command::Down => {
-                if row + 1 < self.map_settings.size {
+                if row + 1 < self.map_settings.height() {
                     Some((row + 1, col))
                 } else {
                     None
@@ -780,48 +788,46 @@ impl NeuralBot {
             }
             Command::Left => col.checked_sub(1).map(|c| (row, c)),
             Command::Right => {
-                if col + 1 < self.map_settings.size {
+                if col + 1 < self.map_settings.width() {
                     Some((row, col + 1))
                 } else {
                     None
                 }
             }
-            Command::Wait | Command::PlaceBomb => Some((row, col)),
+            Command::Wait | Command::PlaceBomb | Command::KickBomb(_) | Command::Detonate => Some((row, col)),
         }
     }
 
     fn empty_heatmap(&self) -> Vec<f32> {
-        vec![0.0; self.map_settings.size * self.map_settings.size]
+        vec![0.0; self.map_settings.width() * self.map_settings.height()]
     }
 
-    fn is_wall(&self, map: &Map, row: usize, col: usize) -> bool {
-        let cell = self.get_map_cell(row, col, map);
-        cell == 'W' || cell == '.'
+    fn is_wall(&self, observation: &Observation, row: usize, col: usize) -> bool {
+        let cell = self.get_map_cell(row, col, observation);
+        cell == CellType::Wall || cell == CellType::Destroyable
     }
 
-    fn is_clear(&self, map: &Map, row: usize, col: usize) -> bool {
-        if let Some(shrink) = self.next_shrink_location {
-            if shrink.row.get() == row && shrink.col.get() == col {
-                return false;
-            }
+    fn is_clear(&self, observation: &Observation, row: usize, col: usize) -> bool {
+        if self.next_shrink_locations.contains(&Coord::from(col, row)) {
+            return false;
         }
-        self.get_map_cell(row, col, map) == ' '
+        self.get_map_cell(row, col, observation) == CellType::Empty
     }
 
-    fn get_map_cell(&self, row: usize, col: usize, map: &Map) -> char {
-        self.get_grid_value(&map.grid.tiles, row, col)
+    fn get_map_cell(&self, row: usize, col: usize, observation: &Observation) -> CellType {
+        self.get_grid_value(&observation.grid, row, col)
     }
 
-    fn get_grid_value<T: Copy>(&self, grid: &Vec<T>, row: usize, col: usize) -> T {
+    fn get_grid_value<T: Copy>(&self, grid: &[T], row: usize, col: usize) -> T {
         grid[self.idx(row, col)]
     }
 
     fn idx(&self, row: usize, col: usize) -> usize {
-        row * self.map_settings.size + col
+        row * self.map_settings.width() + col
     }
 
     fn out_of_bounds(&self, row: usize, col: usize) -> bool {
-        row >= self.map_settings.size || col >= self.map_settings.size
+        row >= self.map_settings.height() || col >= self.map_settings.width()
     }
 
     fn get_correct_init_list(loc: Coord, height: i32, width: i32) -> Vec<Command> {
@@ -857,20 +863,20 @@ impl NeuralBot {
 }
 
 impl Bot for NeuralBot {
-    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = settings.clone();
-        self.turn = 0;
-        self.next_shrink_location = None;
+        self.next_shrink_locations.clear();
         self.last_debug.clear();
         true
     }
 
-    fn get_move(&mut self, map: &Map, player_location: Coord) -> Command {
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let player_location = observation.me.position;
         if !self.initialized {
-            let height = self.map_settings.size as i32;
-            let width = self.map_settings.size as i32;
+            let height = self.map_settings.height() as i32;
+            let width = self.map_settings.width() as i32;
 
             self.command_list = NeuralBot::get_correct_init_list(player_location, height, width);
 
@@ -882,12 +888,13 @@ impl Bot for NeuralBot {
         //max size game 19x19 = 187x17
         // Run initial script
         if !self.looping {
+            let size = self.map_settings.width().min(self.map_settings.height());
             if self.current_index < self.command_list.len() 
-            && (self.map_settings.size != 7 || self.current_index < 2)
-            && (self.map_settings.size != 9 || self.current_index < 18) 
-            && (self.map_settings.size != 11 || self.current_index < 18)
-            && (self.map_settings.size != 13 || self.current_index < 27)
-            && (self.map_settings.size != 15 || self.current_index < 51)
+            && (size != 7 || self.current_index < 2)
+            && (size != 9 || self.current_index < 18) 
+            && (size != 11 || self.current_index < 18)
+            && (size != 13 || self.current_index < 27)
+            && (size != 15 || self.current_index < 51)
             {
                 let cmd = self.command_list[self.current_index];
                 self.current_index += 1;
@@ -898,17 +905,8 @@ impl Bot for NeuralBot {
             self.current_index = 0;
         }
 
-        if map.map_settings.endgame <= self.turn {
-            self.next_shrink_location = calculate_shrink_location(
-                self.turn - map.map_settings.endgame,
-                map.map_settings.size,
-            );
-        } else {
-            self.next_shrink_location = None;
-        }
-
-        self.turn += 1;
-        self.decide_move(map, player_location)
+        self.next_shrink_locations = observation.shrink_this_turn();
+        self.decide_move(observation, player_location)
     }
 
     fn get_debug_info(&self) -> String {
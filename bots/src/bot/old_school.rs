@@ -1,9 +1,8 @@
-use rand::Rng;
 use game::bot::bot::Bot;
-use game::coord::Coord;
+use game::bot::observation::Observation;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
 
 pub struct OldSchool {
     name: String,
@@ -35,19 +34,20 @@ impl OldSchool {
 
 impl Bot for OldSchool {
 
-    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = settings.clone();
         false
     }
 
-    fn get_move(&mut self, map: &Map, me: Coord) -> Command {
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let me = observation.me.position;
         let row = me.row.get() as isize;
         let column = me.col.get() as isize;
 
-        let max_row = (self.map_settings.size - 2) as isize;
-        let max_column = (self.map_settings.size - 2) as isize;
+        let max_row = (self.map_settings.height() - 2) as isize;
+        let max_column = (self.map_settings.width() - 2) as isize;
 
         if !self.nextmoves.is_empty() {
             return self.nextmoves.pop().unwrap();
@@ -144,6 +144,6 @@ impl Bot for OldSchool {
     }
 
     fn get_debug_info(&self) -> String {
-        return self.debug_info.clone();
+        self.debug_info.clone()
     }
 }
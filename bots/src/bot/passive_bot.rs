@@ -1,8 +1,9 @@
 use game::bot::bot::Bot;
+use game::bot::observation::Observation;
 use game::coord::Coord;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
 
 #[derive(Clone)]
 pub struct PassiveBot {
@@ -27,8 +28,8 @@ impl PassiveBot {
     }
 
     /// Is `loc` in the straight-line blast zone of any bomb (current dont care about timer)
-    fn is_danger(&self, map: &Map, loc: Coord) -> bool {
-        map.bombs
+    fn is_danger(&self, observation: &Observation, loc: Coord) -> bool {
+        observation.bombs
             .iter()
             .filter(|b| b.timer <= self.map_settings.bomb_timer) //now all can be changed later
             .any(|b| {
@@ -45,7 +46,7 @@ impl PassiveBot {
     }
 
     /// All legal moves (Up/Down/Left/Right/Wait) that land on a space and aren’t dangerous.
-    fn safe_moves(&self, map: &Map, me: Coord) -> Vec<(Command, Coord)> {
+    fn safe_moves(&self, observation: &Observation, me: Coord) -> Vec<(Command, Coord)> {
         let mut opts = Vec::new();
         for &(cmd, neighbor) in &[
             (Command::Up, me.move_up()),
@@ -55,8 +56,7 @@ impl PassiveBot {
             (Command::Wait, Some(me)),
         ] {
             if let Some(nc) = neighbor {
-                let idx = nc.row.get() * map.map_settings.size + nc.col.get();
-                if map.grid.tiles[idx] == ' ' && !self.is_danger(map, nc) {
+                if observation.is_empty(nc) && !self.is_danger(observation, nc) {
                     opts.push((cmd, nc));
                 }
             }
@@ -64,9 +64,9 @@ impl PassiveBot {
         opts
     }
 
-    fn get_best_safe_move(&self, map: &Map, safe: &[(Command, Coord)]) -> Command {
-        let center_row = map.map_settings.size / 2;
-        let center_col = map.map_settings.size / 2;
+    fn get_best_safe_move(&self, observation: &Observation, safe: &[(Command, Coord)]) -> Command {
+        let center_row = observation.height / 2;
+        let center_col = observation.width / 2;
 
         let best = safe
             .iter()
@@ -75,7 +75,7 @@ impl PassiveBot {
                 let col_diff = (coord.col.get() as isize - center_col as isize).abs();
                 let center_score = -(row_diff + col_diff);
 
-                let escape_routes = self.safe_moves(map, *coord).len();
+                let escape_routes = self.safe_moves(observation, *coord).len();
 
                 center_score * 2 + escape_routes as isize
             })
@@ -86,18 +86,18 @@ impl PassiveBot {
 }
 
 impl Bot for PassiveBot {
-    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, settings: &MapConfig, bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
         self.map_settings = settings.clone();
         true
     }
 
-    fn get_move(&mut self, map: &Map, me: Coord) -> Command {
-        let safe = self.safe_moves(map, me);
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let safe = self.safe_moves(observation, observation.me.position);
 
         if !safe.is_empty() {
-            return self.get_best_safe_move(map, &safe);
+            return self.get_best_safe_move(observation, &safe);
         }
 
         // (3) Else, wait.
@@ -1,22 +1,22 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use game::bot::bot::Bot;
-use game::coord::Coord;
+use game::bot::observation::Observation;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
 
 #[derive(Clone)]
 pub struct RandomBot {
     pub name: String,
     pub id: usize,
+    rng: StdRng,
 }
 
 impl RandomBot {}
 
 impl Bot for RandomBot {
-    fn get_move(&mut self, _map: &Map, _player_location: Coord) -> Command {
+    fn get_move(&mut self, _observation: &Observation) -> Command {
         // Randomly choose a command for the bot
-        use rand::Rng;
-        let mut rng = rand::rng();
         let commands = [
             Command::Up,
             Command::Down,
@@ -25,12 +25,13 @@ impl Bot for RandomBot {
             Command::Wait,
             // Command::PlaceBomb,
         ];
-        commands[rng.random_range(0..commands.len())]
+        commands[self.rng.random_range(0..commands.len())]
     }
 
-    fn start_game(&mut self, _: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, _: &MapConfig, bot_name: String, bot_id: usize, rng: StdRng) -> bool {
         self.id = bot_id;
         self.name = bot_name;
+        self.rng = rng;
         true
     }
 }
@@ -46,6 +47,7 @@ impl RandomBot {
         RandomBot {
             name: "RandomBot".to_string(),
             id: 0,
+            rng: StdRng::seed_from_u64(0),
         }
     }
 }
@@ -1,8 +1,8 @@
 use game::bot::bot::Bot;
-use game::coord::Coord;
+use game::bot::observation::Observation;
 use game::map::enums::command::Command;
-use game::map::map::Map;
 use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
 
 /// Template struct for implementing a new bot
 #[derive(Clone)]
@@ -25,14 +25,15 @@ impl TemplateBot {
 
 impl Bot for TemplateBot {
     /// Called once at the start of the game to initialize the bot
-    fn start_game(&mut self, _settings: &MapConfig, bot_name: String, bot_id: usize) -> bool {
+    fn start_game(&mut self, _settings: &MapConfig, bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
         self.name = bot_name;
         self.id = bot_id;
         true
     }
 
-    /// Called each turn to determine the bot's move
-    fn get_move(&mut self, _map: &Map, _player_location: Coord) -> Command {
+    /// Called each turn to determine the bot's move.
+    /// The observation also holds the turn number and the cells that shrink next.
+    fn get_move(&mut self, _observation: &Observation) -> Command {
         Command::Wait
     }
 
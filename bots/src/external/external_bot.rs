@@ -0,0 +1,179 @@
+use std::io::{BufRead, BufReader, Write};
+use std::process::{Child, ChildStdin, Command as Process, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+use game::bot::bot::{Bot, BotConstructor, BotController};
+use game::bot::observation::Observation;
+use game::map::enums::command::Command;
+use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
+use serde::de::DeserializeOwned;
+
+use super::protocol::{EngineMessage, MoveResponse, StartResponse};
+
+/// A bot running as a separate process, talking the line based JSON protocol in `protocol`.
+///
+/// The process is started in `start_game` and killed when the bot is dropped, so every game
+/// gets a fresh process. A process that crashes, answers garbage or does not answer within
+/// `response_timeout` makes the bot panic, which disqualifies it for the game.
+pub struct ExternalBot {
+    program: String,
+    args: Vec<String>,
+    response_timeout: Duration,
+    process: Option<ExternalProcess>,
+    debug_info: String,
+}
+
+struct ExternalProcess {
+    child: Child,
+    stdin: ChildStdin,
+    lines: Receiver<String>,
+}
+
+impl ExternalBot {
+    pub fn new(program: String, args: Vec<String>) -> Self {
+        Self {
+            program,
+            args,
+            response_timeout: Duration::from_secs(1),
+            process: None,
+            debug_info: String::new(),
+        }
+    }
+
+    pub fn with_response_timeout(mut self, response_timeout: Duration) -> Self {
+        self.response_timeout = response_timeout;
+        self
+    }
+
+    /// Creates a constructor so an external bot can join a tournament next to the built-in bots.
+    pub fn constructor(name: String, program: String, args: Vec<String>) -> BotConstructor {
+        Box::new(move || {
+            BotController::new(Box::new(ExternalBot::new(program.clone(), args.clone())), name.clone())
+        })
+    }
+
+    fn spawn(&self) -> ExternalProcess {
+        let mut child = Process::new(&self.program)
+            .args(&self.args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .unwrap_or_else(|err| panic!("Could not start external bot '{}': {err}", self.program));
+
+        let stdin = child.stdin.take().expect("stdin is piped");
+        let stdout = child.stdout.take().expect("stdout is piped");
+
+        // Reading happens on a separate thread, so a bot that hangs can be timed out
+        let (sender, lines) = mpsc::channel();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send(line).is_err() {
+                    break;
+                }
+            }
+        });
+
+        ExternalProcess { child, stdin, lines }
+    }
+
+    fn request<T: DeserializeOwned>(&mut self, message: &EngineMessage) -> T {
+        let timeout = self.response_timeout;
+        let process = self.process.as_mut().expect("External bot used before start_game");
+
+        let line = serde_json::to_string(message).expect("Engine messages always serialize");
+        writeln!(process.stdin, "{line}")
+            .and_then(|_| process.stdin.flush())
+            .unwrap_or_else(|err| panic!("External bot stopped reading: {err}"));
+
+        let response = process
+            .lines
+            .recv_timeout(timeout)
+            .unwrap_or_else(|_| panic!("External bot did not answer within {timeout:?}"));
+        serde_json::from_str(&response).unwrap_or_else(|err| panic!("Invalid answer from external bot '{response}': {err}"))
+    }
+}
+
+impl Bot for ExternalBot {
+    fn start_game(&mut self, map_settings: &MapConfig, bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
+        self.process = Some(self.spawn());
+        let response: StartResponse = self.request(&EngineMessage::Start {
+            name: bot_name,
+            id: bot_id,
+            settings: map_settings.clone(),
+        });
+        response.ready
+    }
+
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let response: MoveResponse = self.request(&EngineMessage::Turn(observation.clone()));
+        self.debug_info = response.debug;
+        response.command
+    }
+
+    fn get_debug_info(&self) -> String {
+        self.debug_info.clone()
+    }
+}
+
+impl Drop for ExternalBot {
+    fn drop(&mut self) {
+        if let Some(process) = self.process.as_mut() {
+            let _ = process.child.kill();
+            let _ = process.child.wait();
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use game::game::game::Game;
+
+    /// Stand-in for a real external bot: answers the start message and then always places a bomb.
+    const STAND_IN_SCRIPT: &str = r#"
+        while read line; do
+            case "$line" in
+                *'"type":"start"'*) echo '{"ready":true}' ;;
+                *) echo '{"command":"PlaceBomb","debug":"boom"}' ;;
+            esac
+        done
+    "#;
+
+    fn stand_in_bot(script: &str) -> BotController {
+        let bot = ExternalBot::new("sh".to_string(), vec!["-c".to_string(), script.to_string()]);
+        BotController::new(Box::new(bot), "External".to_string())
+    }
+
+    #[test]
+    fn test_external_bot_plays_moves_from_process() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let mut game = Game::build(vec![stand_in_bot(STAND_IN_SCRIPT), stand_in_bot(STAND_IN_SCRIPT)], map_settings, None).unwrap();
+
+        //Act
+        game.run_round(None).unwrap();
+
+        //Assert
+        assert!(matches!(game.player_actions[0][0], Command::PlaceBomb));
+        assert_eq!(game.debug_info[1][0], "boom");
+    }
+
+    #[test]
+    fn test_external_bot_with_invalid_answer_is_disqualified() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let broken = "read line; echo '{\"ready\":true}'; read line; echo 'not json'";
+        let mut game = Game::build(vec![stand_in_bot(STAND_IN_SCRIPT), stand_in_bot(broken)], map_settings, None).unwrap();
+
+        //Act
+        let result = game.run().unwrap();
+
+        //Assert
+        assert!(result.players[1].panic.as_ref().unwrap().contains("Invalid answer"));
+        assert_eq!(result.winner, "External (0)");
+    }
+}
@@ -0,0 +1,2 @@
+pub mod external_bot;
+pub mod protocol;
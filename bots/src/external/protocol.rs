@@ -0,0 +1,40 @@
+//! Line based JSON protocol between the engine and an out-of-process bot.
+//!
+//! Every message is a single JSON object on its own line.
+//!
+//! Engine to bot, on stdin:
+//! - `{"type":"start","name":"MyBot (1)","id":1,"settings":{"size":11,...}}` once per game.
+//! - `{"type":"turn","turn":0,"width":11,"height":11,"grid":["W","W",...],"me":{...},"opponents":[...],...}` every turn.
+//!   This is an `Observation`, the same structure the built-in bots receive.
+//!
+//! Bot to engine, on stdout:
+//! - `{"ready":true}` after the start message.
+//! - `{"command":"PlaceBomb","debug":"optional text"}` after every turn message.
+//!   The command is one of `Up`, `Down`, `Left`, `Right`, `Wait` or `PlaceBomb`, and when the
+//!   settings allow them `{"KickBomb":"Up"}` or `Detonate`.
+//!
+//! Anything a bot writes to stderr is passed through and can be used for logging.
+
+use serde::{Deserialize, Serialize};
+use game::bot::observation::Observation;
+use game::map::enums::command::Command;
+use game::map::structs::map_config::MapConfig;
+
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EngineMessage {
+    Start { name: String, id: usize, settings: MapConfig },
+    Turn(Observation),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartResponse {
+    pub ready: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveResponse {
+    pub command: Command,
+    #[serde(default)]
+    pub debug: String,
+}
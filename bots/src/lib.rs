@@ -1,6 +1,9 @@
 #![forbid(unsafe_code)]
 
 mod bot;
+pub mod external;
+#[cfg(feature = "wasm-plugins")]
+pub mod wasm;
 
 use game::bot::bot::BotConstructor;
 
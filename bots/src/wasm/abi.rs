@@ -0,0 +1,196 @@
+//! Stable ABI between the engine and a WebAssembly bot module.
+//!
+//! A module must export:
+//! - `memory`: its linear memory.
+//! - `alloc(len: i32) -> i32`: returns a pointer to `len` writable bytes for the engine to use.
+//! - `get_move(ptr: i32, len: i32) -> i32`: receives the encoded turn state and returns a command code.
+//!
+//! It may export `start_game(id: i32, width: i32, height: i32, bomb_timer: i32, bomb_radius: i32, endgame: i32)`,
+//! which is called once before the first move.
+//!
+//! The turn state holds the whole `Observation` as little endian `u32` values, then the grid and the names:
+//! - `version, turn, width, height, my_col, my_row, player_count, bomb_count, max_turn, endgame,
+//!   turns_until_shrink, shrink_count`.
+//! - Per player `id, col, row, extra_bombs, extra_radius, extra_speed, active_bombs, bomb_capacity`,
+//!   where a `bomb_capacity` of `u32::MAX` means no limit. The players are the bot itself followed by
+//!   the opponents that are still alive.
+//! - Per bomb `col, row, timer, owner, radius`.
+//! - Per cell that shrinks within the next turns `turn, col, row`, in the order they shrink.
+//! - `width * height` bytes with the tiles row by row, using the characters of `CellType`.
+//! - Per player a `u32` length and the UTF-8 bytes of its name.
+//!
+//! Version 1 had a single `size` instead of `width, height`. Version 2 ended after the grid, had
+//! `id, col, row, alive` per player and `col, row, timer, owner` per bomb.
+//!
+//! Command codes: 0 `Up`, 1 `Down`, 2 `Left`, 3 `Right`, 4 `Wait`, 5 `PlaceBomb`,
+//! 6 to 9 `KickBomb` up, down, left and right, and 10 `Detonate`.
+
+use game::bot::observation::{ObservedBomb, ObservedPlayer, ObservedShrink, Observation};
+use game::coord::Coord;
+use game::map::enums::command::Command;
+use game::map::enums::direction::Direction;
+use game::map::grid::cell::CellType;
+use game::map::structs::player_stats::PlayerStats;
+
+pub const ABI_VERSION: u32 = 3;
+/// `bomb_capacity` of a player without a limit.
+const NO_CAPACITY: u32 = u32::MAX;
+
+pub fn encode_state(observation: &Observation) -> Vec<u8> {
+    let me = &observation.me;
+    let players: Vec<&ObservedPlayer> = std::iter::once(me).chain(&observation.opponents).collect();
+    let mut words = vec![
+        ABI_VERSION,
+        observation.turn as u32,
+        observation.width as u32,
+        observation.height as u32,
+        me.position.col.get() as u32,
+        me.position.row.get() as u32,
+        players.len() as u32,
+        observation.bombs.len() as u32,
+        observation.max_turn as u32,
+        observation.endgame as u32,
+        observation.turns_until_shrink as u32,
+        observation.next_shrink_cells.len() as u32,
+    ];
+    for player in &players {
+        words.extend([
+            player.id as u32,
+            player.position.col.get() as u32,
+            player.position.row.get() as u32,
+            player.stats.extra_bombs as u32,
+            player.stats.extra_radius as u32,
+            player.stats.extra_speed as u32,
+            player.active_bombs as u32,
+            player.bomb_capacity.map_or(NO_CAPACITY, |capacity| capacity as u32),
+        ]);
+    }
+    for bomb in &observation.bombs {
+        words.extend([bomb.position.col.get() as u32, bomb.position.row.get() as u32, bomb.timer as u32, bomb.owner as u32, bomb.radius as u32]);
+    }
+    for shrink in &observation.next_shrink_cells {
+        words.extend([shrink.turn as u32, shrink.position.col.get() as u32, shrink.position.row.get() as u32]);
+    }
+
+    let mut bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    bytes.extend(observation.grid.iter().map(|cell| cell.as_char() as u8));
+    for player in &players {
+        bytes.extend((player.name.len() as u32).to_le_bytes());
+        bytes.extend(player.name.as_bytes());
+    }
+    bytes
+}
+
+/// Reads a turn state written by `encode_state`, `None` when it is not a valid version 3 state.
+pub fn decode_state(bytes: &[u8]) -> Option<Observation> {
+    let mut reader = StateReader { bytes, position: 0 };
+    let [version, turn, width, height, _my_col, _my_row, player_count, bomb_count, max_turn, endgame, turns_until_shrink, shrink_count] = reader.words()?;
+    if version != ABI_VERSION {
+        return None;
+    }
+
+    let mut players = Vec::new();
+    for _ in 0..player_count {
+        let [id, col, row, extra_bombs, extra_radius, extra_speed, active_bombs, bomb_capacity] = reader.words()?;
+        players.push(ObservedPlayer {
+            id: id as usize,
+            name: String::new(),
+            position: Coord::from(col as usize, row as usize),
+            stats: PlayerStats { extra_bombs: extra_bombs as usize, extra_radius: extra_radius as usize, extra_speed: extra_speed as usize },
+            active_bombs: active_bombs as usize,
+            bomb_capacity: (bomb_capacity != NO_CAPACITY).then_some(bomb_capacity as usize),
+        });
+    }
+    let mut bombs = Vec::new();
+    for _ in 0..bomb_count {
+        let [col, row, timer, owner, radius] = reader.words()?;
+        bombs.push(ObservedBomb { position: Coord::from(col as usize, row as usize), timer: timer as usize, owner: owner as usize, radius: radius as usize });
+    }
+    let mut next_shrink_cells = Vec::new();
+    for _ in 0..shrink_count {
+        let [turn, col, row] = reader.words()?;
+        next_shrink_cells.push(ObservedShrink { turn: turn as usize, position: Coord::from(col as usize, row as usize) });
+    }
+    let grid = reader.bytes(width as usize * height as usize)?.iter().map(|&tile| CellType::from_char(tile as char)).collect();
+    for player in &mut players {
+        let [length] = reader.words()?;
+        player.name = String::from_utf8(reader.bytes(length as usize)?.to_vec()).ok()?;
+    }
+
+    let mut players = players.into_iter();
+    Some(Observation {
+        turn: turn as usize,
+        max_turn: max_turn as usize,
+        endgame: endgame as usize,
+        width: width as usize,
+        height: height as usize,
+        grid,
+        me: players.next()?,
+        opponents: players.collect(),
+        bombs,
+        turns_until_shrink: turns_until_shrink as usize,
+        next_shrink_cells,
+    })
+}
+
+struct StateReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> StateReader<'a> {
+    fn bytes(&mut self, length: usize) -> Option<&'a [u8]> {
+        let bytes = self.bytes.get(self.position..self.position.checked_add(length)?)?;
+        self.position += length;
+        Some(bytes)
+    }
+
+    fn words<const N: usize>(&mut self) -> Option<[u32; N]> {
+        let bytes = self.bytes(N * 4)?;
+        Some(std::array::from_fn(|i| u32::from_le_bytes(bytes[i * 4..i * 4 + 4].try_into().unwrap())))
+    }
+}
+
+pub fn decode_command(code: i32) -> Option<Command> {
+    match code {
+        0 => Some(Command::Up),
+        1 => Some(Command::Down),
+        2 => Some(Command::Left),
+        3 => Some(Command::Right),
+        4 => Some(Command::Wait),
+        5 => Some(Command::PlaceBomb),
+        6 => Some(Command::KickBomb(Direction::Up)),
+        7 => Some(Command::KickBomb(Direction::Down)),
+        8 => Some(Command::KickBomb(Direction::Left)),
+        9 => Some(Command::KickBomb(Direction::Right)),
+        10 => Some(Command::Detonate),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::bot::bot_data::BotData;
+    use game::game::game::Game;
+    use game::map::enums::shrink_pattern::ShrinkPattern;
+    use game::map::structs::map_config::MapConfig;
+
+    #[test]
+    fn test_state_round_trips_the_whole_observation() {
+        //Arrange: shrinks right away, so the observation has shrink cells
+        let map_settings = MapConfig { size: 7, endgame: 2, shrink: ShrinkPattern::Spiral, max_bombs_per_player: Some(2), ..Default::default() };
+        let bot_data = vec![BotData { id: 0, name: "first".to_string() }, BotData { id: 1, name: "second".to_string() }];
+        let mut game = Game::build(Vec::new(), map_settings, Some(bot_data)).unwrap();
+        game.run_round(Some(&vec![vec![Command::PlaceBomb], vec![Command::Wait]])).unwrap();
+        let observation = Observation::from_map(&game.map, game.turn, 0).unwrap();
+
+        //Act
+        let decoded = decode_state(&encode_state(&observation));
+
+        //Assert
+        assert!(!observation.bombs.is_empty());
+        assert!(!observation.next_shrink_cells.is_empty());
+        assert_eq!(decoded, Some(observation));
+    }
+}
@@ -0,0 +1,246 @@
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+
+use game::bot::bot::{Bot, BotConstructor, BotController};
+use game::bot::observation::Observation;
+use game::map::enums::command::Command;
+use game::map::structs::map_config::MapConfig;
+use rand::rngs::StdRng;
+use wasmi::core::TrapCode;
+use wasmi::{Config, Engine, Instance, Linker, Memory, Module, Store, StoreLimits, StoreLimitsBuilder, TypedFunc};
+
+use super::abi::{decode_command, encode_state};
+
+/// Fuel a bot may burn for a single move, roughly the number of executed instructions.
+pub const DEFAULT_FUEL_PER_MOVE: u64 = 50_000_000;
+/// Largest linear memory a bot may have. Growing beyond it fails inside the module, like running out of memory.
+pub const MAX_MEMORY_BYTES: usize = 64 * 1024 * 1024;
+
+/// A sandboxed bot compiled to WebAssembly, see `abi` for what the module has to export.
+///
+/// Execution is metered with fuel instead of wall clock time, so the limit is the same on every
+/// machine and games stay reproducible. A move that runs out of fuel becomes `Wait`; any other
+/// trap or an unknown command code makes the bot panic, which disqualifies it for the game.
+/// Memory is capped at `MAX_MEMORY_BYTES` per bot.
+pub struct WasmBot {
+    module: Arc<Module>,
+    fuel_per_move: u64,
+    runtime: Option<WasmRuntime>,
+    debug_info: String,
+}
+
+struct WasmRuntime {
+    store: Store<StoreLimits>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    get_move: TypedFunc<(i32, i32), i32>,
+}
+
+impl WasmBot {
+    pub fn new(module: Arc<Module>, fuel_per_move: u64) -> Self {
+        Self {
+            module,
+            fuel_per_move,
+            runtime: None,
+            debug_info: String::new(),
+        }
+    }
+
+    /// Creates an engine with fuel metering, needed to compile modules for `WasmBot`.
+    pub fn engine() -> Engine {
+        let mut config = Config::default();
+        config.consume_fuel(true);
+        Engine::new(&config)
+    }
+
+    /// Compiles every `.wasm` file in `dir` into a bot constructor, named after the file.
+    pub fn load_dir(dir: &Path, fuel_per_move: u64) -> Result<Vec<BotConstructor>, String> {
+        let engine = Self::engine();
+        let mut paths: Vec<_> = fs::read_dir(dir)
+            .map_err(|err| format!("Could not read {}: {err}", dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|extension| extension == "wasm"))
+            .collect();
+        paths.sort();
+
+        paths
+            .iter()
+            .map(|path| {
+                let bytes = fs::read(path).map_err(|err| format!("Could not read {}: {err}", path.display()))?;
+                let module = Module::new(&engine, &bytes).map_err(|err| format!("Invalid module {}: {err}", path.display()))?;
+                let name = path.file_stem().map_or("WasmBot".to_string(), |stem| stem.to_string_lossy().to_string());
+                Ok(Self::constructor(name, Arc::new(module), fuel_per_move))
+            })
+            .collect()
+    }
+
+    pub fn constructor(name: String, module: Arc<Module>, fuel_per_move: u64) -> BotConstructor {
+        Box::new(move || BotController::new(Box::new(WasmBot::new(module.clone(), fuel_per_move)), name.clone()))
+    }
+
+    fn instantiate(&self, map_settings: &MapConfig, bot_id: usize) -> Result<WasmRuntime, wasmi::Error> {
+        let limits = StoreLimitsBuilder::new().memory_size(MAX_MEMORY_BYTES).build();
+        let mut store = Store::new(self.module.engine(), limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(self.fuel_per_move).expect("fuel metering is enabled");
+        let linker = Linker::<StoreLimits>::new(self.module.engine());
+        let instance: Instance = linker.instantiate(&mut store, &self.module)?.start(&mut store)?;
+
+        let memory = instance
+            .get_memory(&store, "memory")
+            .ok_or_else(|| wasmi::Error::new("module does not export memory"))?;
+        let alloc = instance.get_typed_func::<i32, i32>(&store, "alloc")?;
+        let get_move = instance.get_typed_func::<(i32, i32), i32>(&store, "get_move")?;
+
+        if let Ok(start_game) = instance.get_typed_func::<(i32, i32, i32, i32, i32, i32), ()>(&store, "start_game") {
+            start_game.call(
+                &mut store,
+                (
+                    bot_id as i32,
+                    map_settings.width() as i32,
+                    map_settings.height() as i32,
+                    map_settings.bomb_timer as i32,
+                    map_settings.bomb_radius as i32,
+                    map_settings.endgame as i32,
+                ),
+            )?;
+        }
+
+        Ok(WasmRuntime { store, memory, alloc, get_move })
+    }
+}
+
+impl WasmRuntime {
+    fn get_move(&mut self, state: &[u8], fuel: u64) -> Result<i32, wasmi::Error> {
+        self.store.set_fuel(fuel).expect("fuel metering is enabled");
+        let ptr = self.alloc.call(&mut self.store, state.len() as i32)?;
+        self.memory.write(&mut self.store, ptr as usize, state)?;
+        self.get_move.call(&mut self.store, (ptr, state.len() as i32))
+    }
+}
+
+impl Bot for WasmBot {
+    fn start_game(&mut self, map_settings: &MapConfig, _bot_name: String, bot_id: usize, _rng: StdRng) -> bool {
+        let runtime = self
+            .instantiate(map_settings, bot_id)
+            .unwrap_or_else(|err| panic!("Could not start wasm bot: {err}"));
+        self.runtime = Some(runtime);
+        true
+    }
+
+    fn get_move(&mut self, observation: &Observation) -> Command {
+        let state = encode_state(observation);
+        let runtime = self.runtime.as_mut().expect("Wasm bot used before start_game");
+
+        match runtime.get_move(&state, self.fuel_per_move) {
+            Ok(code) => {
+                self.debug_info.clear();
+                decode_command(code).unwrap_or_else(|| panic!("Wasm bot returned unknown command code {code}"))
+            }
+            Err(err) if err.as_trap_code() == Some(TrapCode::OutOfFuel) => {
+                self.debug_info = "out of fuel".to_string();
+                Command::Wait
+            }
+            Err(err) => panic!("Wasm bot trapped: {err}"),
+        }
+    }
+
+    fn get_debug_info(&self) -> String {
+        self.debug_info.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game::game::game::Game;
+
+    /// Reads `my_col` from the state and places a bomb when it is odd, otherwise waits.
+    const BOMB_ON_ODD_COLUMN: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32) (i32.const 1024))
+            (func (export "get_move") (param $ptr i32) (param $len i32) (result i32)
+                (if (result i32) (i32.and (i32.load offset=16 (local.get $ptr)) (i32.const 1))
+                    (then (i32.const 5))
+                    (else (i32.const 4)))))
+    "#;
+
+    const ENDLESS_LOOP: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32) (i32.const 0))
+            (func (export "get_move") (param i32 i32) (result i32)
+                (loop $forever (br $forever))
+                (i32.const 0)))
+    "#;
+
+    /// Tries to grow its memory by 128 MiB every move and waits when that fails.
+    const MEMORY_HOG: &str = r#"
+        (module
+            (memory (export "memory") 1)
+            (func (export "alloc") (param i32) (result i32) (i32.const 0))
+            (func (export "get_move") (param i32 i32) (result i32)
+                (if (result i32) (i32.eq (memory.grow (i32.const 2048)) (i32.const -1))
+                    (then (i32.const 4))
+                    (else (i32.const 5)))))
+    "#;
+
+    fn wasm_bot(wat: &str, name: &str) -> BotController {
+        wasm_bot_with_fuel(wat, name, 100_000)
+    }
+
+    fn wasm_bot_with_fuel(wat: &str, name: &str, fuel_per_move: u64) -> BotController {
+        let module = Module::new(&WasmBot::engine(), &wat::parse_str(wat).unwrap()).unwrap();
+        WasmBot::constructor(name.to_string(), Arc::new(module), fuel_per_move)()
+    }
+
+    #[test]
+    fn test_wasm_bot_reads_state_and_returns_command() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let bots = vec![wasm_bot(BOMB_ON_ODD_COLUMN, "first"), wasm_bot(BOMB_ON_ODD_COLUMN, "second")];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        game.run_round(None).unwrap();
+
+        //Assert
+        assert!(matches!(game.player_actions[0][0], Command::PlaceBomb));
+        assert!(matches!(game.player_actions[1][0], Command::PlaceBomb));
+    }
+
+    #[test]
+    fn test_wasm_bot_out_of_fuel_waits() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let bots = vec![wasm_bot(ENDLESS_LOOP, "looper"), wasm_bot(BOMB_ON_ODD_COLUMN, "other")];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        game.run_round(None).unwrap();
+
+        //Assert
+        assert!(matches!(game.player_actions[0][0], Command::Wait));
+        assert_eq!(game.debug_info[0][0], "out of fuel");
+        assert!(game.map.get_alive_players_ids().contains(&0));
+    }
+
+    #[test]
+    fn test_wasm_bot_memory_is_capped() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        // Enough fuel to pay for the growth, so only the memory cap can stop it
+        let bots = vec![wasm_bot_with_fuel(MEMORY_HOG, "hog", DEFAULT_FUEL_PER_MOVE), wasm_bot(BOMB_ON_ODD_COLUMN, "other")];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        game.run_round(None).unwrap();
+
+        //Assert
+        assert!(matches!(game.player_actions[0][0], Command::Wait));
+        assert_eq!(game.debug_info[0][0], "");
+        assert!(game.map.get_alive_players_ids().contains(&0));
+    }
+}
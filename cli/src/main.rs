@@ -2,15 +2,174 @@ use std::sync::{
     Arc,
     atomic::{AtomicUsize, AtomicBool, Ordering},
 };
+use std::path::Path;
 use std::thread;
 use std::time::{Duration, Instant};
 
 use bots::available_bots;
-use tournament::{factories::game_config_factory::ConfigFactory};
-use tournament::tournament::run_tournament;
+use bots::external::external_bot::ExternalBot;
+use bots::wasm::wasm_bot::{WasmBot, DEFAULT_FUEL_PER_MOVE};
+use game::bot::bot::BotConstructor;
+use game::game::replay_file::ReplayFile;
+use game::game::replay_format::ReplayFormat;
+use game::map::structs::map_layout::MapLayout;
+use game::map::structs::time_budget::TimeBudget;
+use tournament::factories::game_config_factory::{ConfigFactory, GameConfig};
+use tournament::replay_selection::ReplaySelection;
+use tournament::tournament::run_tournament_into;
 use tournament::tournament_result::TournamentResult;
 
+/// External bots passed as `--external "Name=program arg1 arg2"`.
+#[derive(Clone)]
+struct ExternalBotSpec {
+    name: String,
+    program: String,
+    args: Vec<String>,
+}
+
+/// Values of every occurrence of `flag` on the command line.
+fn arg_values(flag: &str) -> Vec<String> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    args.windows(2)
+        .filter(|pair| pair[0] == flag)
+        .map(|pair| pair[1].clone())
+        .collect()
+}
+
+/// Reports a malformed command line and exits, without the backtrace of a panic.
+fn usage_error(message: &str) -> ! {
+    eprintln!("{message}");
+    std::process::exit(2);
+}
+
+/// Parses the number given with `flag`, or returns `default` when the flag is not given.
+fn number_arg(flag: &str, default: usize) -> usize {
+    arg_values(flag)
+        .last()
+        .map_or(default, |value| value.parse().unwrap_or_else(|_| usage_error(&format!("Use {flag} <number>"))))
+}
+
+fn parse_external_bots() -> Vec<ExternalBotSpec> {
+    arg_values("--external")
+        .iter()
+        .map(|value| {
+            let (name, command) = value.split_once('=').unwrap_or_else(|| usage_error("Use --external \"Name=program args\""));
+            let mut parts = command.split_whitespace().map(String::from);
+            ExternalBotSpec {
+                name: name.to_string(),
+                program: parts.next().unwrap_or_else(|| usage_error(&format!("External bot {name} needs a program"))),
+                args: parts.collect(),
+            }
+        })
+        .collect()
+}
+
+fn bot_constructors(external_bots: &[ExternalBotSpec], wasm_dirs: &[String]) -> Vec<BotConstructor> {
+    let mut constructors = available_bots();
+    for spec in external_bots {
+        constructors.push(ExternalBot::constructor(spec.name.clone(), spec.program.clone(), spec.args.clone()));
+    }
+    for dir in wasm_dirs {
+        constructors.extend(WasmBot::load_dir(Path::new(dir), DEFAULT_FUEL_PER_MOVE).unwrap_or_else(|err| usage_error(&err.to_string())));
+    }
+    constructors
+}
+
+/// Tournament configs for the maps passed as `--map path`, or the generated maps when there are none.
+fn tournament_configs() -> Vec<GameConfig> {
+    let layouts: Vec<MapLayout> = arg_values("--map")
+        .iter()
+        .map(|path| MapLayout::load(Path::new(path)).unwrap_or_else(|err| usage_error(&err.to_string())))
+        .collect();
+    if layouts.is_empty() {
+        return ConfigFactory::generate_tournament_configs();
+    }
+    let configs = ConfigFactory::generate_layout_configs(&layouts);
+    for config in &configs {
+        if let Err(err) = config.validate() {
+            let name = config.layout.as_ref().map_or("Custom", |layout| layout.name());
+            usage_error(&format!("Map {name} cannot be played by {} players: {err}", config.num_players));
+        }
+    }
+    configs
+}
+
+/// Time budget of every game, set with `--move-time-ms n` and `--game-time-ms n` where 0 means no
+/// limit. The tournament budget when they are not given.
+fn time_budget() -> TimeBudget {
+    let default = ConfigFactory::tournament_time_budget();
+    let limit = |flag: &str, default: Option<Duration>| {
+        let millis = number_arg(flag, default.map_or(0, |limit| limit.as_millis() as usize));
+        (millis > 0).then(|| Duration::from_millis(millis as u64))
+    };
+    TimeBudget {
+        per_move: limit("--move-time-ms", default.per_move),
+        per_game: limit("--game-time-ms", default.per_game),
+        ..default
+    }
+}
+
+/// Lost games kept when no `--max-losses n` is given.
+const DEFAULT_MAX_LOSSES: usize = 100;
+
+/// Games to save with `--save-replays dir`: the first `--max-losses n` lost games of every
+/// `--save-losses Bot` and the `--save-top n` best scoring games. Only the most interesting game
+/// when neither is given.
+fn replay_selection() -> ReplaySelection {
+    let mut selection = ReplaySelection {
+        losses_of: arg_values("--save-losses"),
+        max_losses: number_arg("--max-losses", DEFAULT_MAX_LOSSES),
+        top: number_arg("--save-top", 0),
+    };
+    if arg_values("--save-replays").is_empty() {
+        return ReplaySelection::default();
+    }
+    if selection.is_empty() {
+        selection.top = 1;
+    }
+    selection
+}
+
+fn replay_format() -> ReplayFormat {
+    match arg_values("--replay-format").last().map(String::as_str) {
+        None | Some("json") => ReplayFormat::Json,
+        Some("binary") => ReplayFormat::Binary,
+        Some(other) => usage_error(&format!("Unknown replay format {other}, use json or binary")),
+    }
+}
+
+/// Writes the selected games to the `--save-replays` directory, exits with status 1 when a replay
+/// could not be saved.
+fn save_replays(result: &TournamentResult, format: ReplayFormat) {
+    let Some(dir) = arg_values("--save-replays").pop() else {
+        return;
+    };
+    let dir = Path::new(&dir);
+    if let Err(err) = std::fs::create_dir_all(dir) {
+        eprintln!("Cannot create {}: {err}", dir.display());
+        std::process::exit(1);
+    }
+    let games = result.saved_games();
+    let mut saved = 0;
+    for game in &games {
+        let path = dir.join(format!("game-{}.{}", game.seed, format.extension()));
+        match ReplayFile::from_result(game).save(&path, format) {
+            Ok(()) => saved += 1,
+            Err(err) => eprintln!("Cannot save {}: {err}", path.display()),
+        }
+    }
+    println!("Saved {saved} replays to {}", dir.display());
+    if saved < games.len() {
+        std::process::exit(1);
+    }
+}
+
 fn main() {
+    let external_bots = parse_external_bots();
+    let wasm_dirs = arg_values("--wasm-dir");
+    let configs = ConfigFactory::with_time_budget(tournament_configs(), &time_budget());
+    let replay_selection = replay_selection();
+    let replay_format = replay_format();
     let num_threads = num_cpus::get();
     println!("Running on {num_threads} threads");
 
@@ -46,35 +205,57 @@ fn main() {
         })
     };
 
-    // Tournament threads
+    // Tournament threads, sharing the bots so wasm modules are compiled once
+    let bot_constructors = Arc::new(bot_constructors(&external_bots, &wasm_dirs));
     let handles: Vec<_> = round_counters
         .into_iter()
         .map(|counter| {
-            let bot_constructors = available_bots();
-            thread::spawn(move || run_tournament(&bot_constructors, Some(counter), duration, ConfigFactory::generate_tournament_configs()))
+            let bot_constructors = bot_constructors.clone();
+            let configs = configs.clone();
+            let result = TournamentResult::new().with_replay_selection(replay_selection.clone());
+            thread::spawn(move || run_tournament_into(result, &bot_constructors, Some(counter), duration, configs))
         })
         .collect();
 
     // Merge results
-    let mut grand_totals = TournamentResult::new();
+    let mut grand_totals = TournamentResult::new().with_replay_selection(replay_selection);
     for handle in handles {
-        grand_totals.merge_with(&mut handle.join().unwrap());
+        match handle.join() {
+            Ok(mut result) => {
+                if let Err(err) = grand_totals.merge_with(&mut result) {
+                    eprintln!("{err}, the results of a tournament thread are skipped");
+                }
+            }
+            Err(_) => eprintln!("A tournament thread crashed, its results are skipped"),
+        }
     }
 
     done.store(true, Ordering::Relaxed);
 
-    // Sort by win percentage
+    // Sort by conservative rating like the web page, win percentage depends too much on the opponents drawn
     let mut sorted_scores: Vec<_> = grand_totals.scores.iter().collect();
     sorted_scores.sort_by(|a, b| {
-        (b.1.wins as f64 / b.1.total_games as f64)
-            .total_cmp(&(a.1.wins as f64 / a.1.total_games as f64))
+        let rating = |bot: &String| grand_totals.ratings.get(bot).map_or(f64::MIN, |rating| rating.conservative());
+        rating(b.0).total_cmp(&rating(a.0))
     });
 
-    println!("Final Scores after {} games:", grand_totals.total_games);
+    println!("Final Scores after {} games ({:?} ratings):", grand_totals.total_games, grand_totals.rating_system);
     for (bot, score) in sorted_scores {
+        let rating = grand_totals.ratings.get(bot).map_or("-".to_string(), |rating| rating.to_string());
         println!(
-            "{bot}: WinPercentage: {:.1}% {score:?}",
+            "{bot}: Rating: {rating} WinPercentage: {:.1}% {score:?}",
             (score.wins as f64 / score.total_games as f64) * 100.0
         );
     }
+
+    if grand_totals.failed_game_count > 0 {
+        eprintln!("{} games could not be played:", grand_totals.failed_game_count);
+        for failed in &grand_totals.failed_games {
+            eprintln!("  {}x{} map for {} players, seed {}: {}", failed.config.width, failed.config.height, failed.config.num_players, failed.config.seed, failed.error);
+        }
+    }
+    if let Some(game) = &grand_totals.most_interesting {
+        println!("Most interesting game: seed {}, winner {}", game.seed, game.winner);
+    }
+    save_replays(&grand_totals, replay_format);
 }
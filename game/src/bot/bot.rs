@@ -3,7 +3,7 @@
 
 // a bot consist of returning a desired name, and a callback that provides player actions based
 // onthe current map and the players positions.
-// the player shoudl self determine how far the game has progressed, includigerhard_bot times.
+// the observation tells how far the game has progressed and where the map shrinks next.
 
 // for every game a bot is constructed. So the lifetime of the bot is the same as the game.
 
@@ -12,8 +12,9 @@
 // Use the macro to include all bot modules automatically
 
 
-use crate::coord::Coord;
-use crate::map::{ map::Map, enums::command::Command };
+use rand::rngs::StdRng;
+use crate::bot::observation::Observation;
+use crate::map::enums::command::Command;
 use crate::map::structs::map_config::MapConfig;
 
 /// Represents a bot that can play the game.
@@ -23,11 +24,17 @@ use crate::map::structs::map_config::MapConfig;
 /// NEW: Bots are now FULLY automatically registered!
 /// Just add a new .rs file to src/bot/ with a struct implementing Bot trait and a new() function.
 /// No manual registration needed anywhere - not even module declarations!
+///
+/// Bots that need randomness should use the `rng` handed to `start_game`. It is seeded from
+/// the game seed, so a game can be reproduced exactly.
+///
+/// Every turn a bot receives an `Observation` of the game, which holds its own position, the turn,
+/// `max_turn` and the shrink schedule as well.
 #[forbid(unsafe_code)]
 pub trait Bot {
-    fn start_game(&mut self, map_settings: &MapConfig,bot_name: String, bot_id: usize) -> bool;
+    fn start_game(&mut self, map_settings: &MapConfig, bot_name: String, bot_id: usize, rng: StdRng) -> bool;
 
-    fn get_move(&mut self, map: &Map, player_location: Coord) -> Command;
+    fn get_move(&mut self, observation: &Observation) -> Command;
 
     fn get_debug_info(&self) -> String {
         "".to_string()
@@ -47,7 +54,7 @@ impl BotController {
         Self {
             inner,
             id: 0,
-            name: name,
+            name,
         }
     }
 
@@ -60,13 +67,13 @@ impl BotController {
         format!("{} ({})", self.name, self.id)
     }
 
-    pub fn start_game(&mut self, map_settings: &MapConfig, bot_id: usize) -> bool {
+    pub fn start_game(&mut self, map_settings: &MapConfig, bot_id: usize, rng: StdRng) -> bool {
         self.id = bot_id;
-        self.inner.start_game(map_settings, self.name.clone(), self.id)
+        self.inner.start_game(map_settings, self.name.clone(), self.id, rng)
     }
 
-    pub fn get_move(&mut self, map: &Map, player_location: Coord) -> Command {
-        self.inner.get_move(map, player_location)
+    pub fn get_move(&mut self, observation: &Observation) -> Command {
+        self.inner.get_move(observation)
     }
 
     pub fn get_debug_info(&self) -> String {
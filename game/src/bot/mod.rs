@@ -1,2 +1,3 @@
 pub mod bot;
-pub mod bot_data;
\ No newline at end of file
+pub mod bot_data;
+pub mod observation;
\ No newline at end of file
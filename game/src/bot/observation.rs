@@ -0,0 +1,217 @@
+use serde::{Deserialize, Serialize};
+use crate::coord::Coord;
+use crate::game::game_error::GameError;
+use crate::map::grid::cell::CellType;
+use crate::map::map::Map;
+use crate::map::player::Player;
+use crate::map::structs::player_stats::PlayerStats;
+
+/// Number of turns ahead for which `Observation::next_shrink_cells` lists the cells that become wall.
+pub const SHRINK_LOOKAHEAD: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObservedPlayer {
+    pub id: usize,
+    pub name: String,
+    pub position: Coord,
+    /// Power-ups collected so far.
+    pub stats: PlayerStats,
+    /// Bombs of this player that are on the map.
+    pub active_bombs: usize,
+    /// Bombs this player can have on the map at once, `None` when there is no limit.
+    pub bomb_capacity: Option<usize>,
+}
+
+/// A cell that becomes wall at the end of `turn`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObservedShrink {
+    pub turn: usize,
+    pub position: Coord,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObservedBomb {
+    pub position: Coord,
+    pub timer: usize,
+    pub owner: usize,
+    pub radius: usize,
+}
+
+/// Everything a bot gets to see on its turn.
+///
+/// It is a copy of the map, so bots do not depend on engine internals. External bots receive the
+/// same structure as JSON.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Observation {
+    pub turn: usize,
+    /// The game ends in a draw at this turn at the latest.
+    pub max_turn: usize,
+    /// First turn at the end of which the map shrinks.
+    pub endgame: usize,
+    pub width: usize,
+    pub height: usize,
+    /// All cells row by row, so `row * width + col`.
+    pub grid: Vec<CellType>,
+    pub me: ObservedPlayer,
+    /// The other players that are still alive.
+    pub opponents: Vec<ObservedPlayer>,
+    pub bombs: Vec<ObservedBomb>,
+    /// Turns left before the map starts shrinking. 0 once it shrinks at the end of every turn.
+    pub turns_until_shrink: usize,
+    /// Cells that become wall within the next `SHRINK_LOOKAHEAD` turns, in the order they shrink.
+    /// Depending on the shrink pattern a turn can shrink several cells or none at all.
+    pub next_shrink_cells: Vec<ObservedShrink>,
+}
+
+impl Observation {
+    pub fn from_map(map: &Map, turn: usize, player_id: usize) -> Result<Self, GameError> {
+        let observe = |player: &Player| ObservedPlayer {
+            id: player.id,
+            name: player.name.clone(),
+            position: player.position,
+            stats: player.stats.clone(),
+            active_bombs: map.active_bombs(player.id),
+            bomb_capacity: map.bomb_capacity(player.id),
+        };
+        let me = map.get_player(player_id).ok_or(GameError::PlayerNotFound { player_id })?;
+        let endgame = map.map_settings.endgame;
+
+        Ok(Observation {
+            turn,
+            max_turn: map.max_turn(),
+            endgame,
+            width: map.map_settings.width(),
+            height: map.map_settings.height(),
+            grid: map.grid.tiles.iter().map(|tile| CellType::from_char(*tile)).collect(),
+            me: observe(me),
+            opponents: map
+                .get_alive_players()
+                .into_iter()
+                .filter(|player| player.id != player_id)
+                .map(observe)
+                .collect(),
+            bombs: map
+                .bombs
+                .iter()
+                .map(|bomb| ObservedBomb { position: bomb.position, timer: bomb.timer, owner: bomb.player_id, radius: bomb.radius })
+                .collect(),
+            turns_until_shrink: endgame.saturating_sub(turn),
+            next_shrink_cells: (turn..turn + SHRINK_LOOKAHEAD)
+                .flat_map(|shrink_turn| map.shrink_cells(shrink_turn).into_iter().map(move |position| ObservedShrink { turn: shrink_turn, position }))
+                .collect(),
+        })
+    }
+
+    /// Returns the cell at `coord`, where everything outside the map counts as wall.
+    pub fn cell(&self, coord: Coord) -> CellType {
+        if !coord.is_valid(self.width, self.height) {
+            return CellType::Wall;
+        }
+        self.grid[coord.row.get() * self.width + coord.col.get()]
+    }
+
+    pub fn is_empty(&self, coord: Coord) -> bool {
+        self.cell(coord) == CellType::Empty
+    }
+
+    /// The cells that become wall at the end of this turn.
+    pub fn shrink_this_turn(&self) -> Vec<Coord> {
+        self.next_shrink_cells
+            .iter()
+            .filter(|shrink| shrink.turn == self.turn)
+            .map(|shrink| shrink.position)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::bomb::Bomb;
+    use crate::map::enums::death_cause::DeathCause;
+    use crate::map::enums::shrink_pattern::ShrinkPattern;
+    use crate::map::shrink::spiral_shrink::calculate_shrink_location;
+    use crate::map::structs::map_config::MapConfig;
+
+    fn map(endgame: usize) -> Map {
+        let players = vec![
+            Player::new("P1".to_string(), Coord::from(1, 1), 0),
+            Player::new("P2".to_string(), Coord::from(5, 5), 1),
+            Player::new("P3".to_string(), Coord::from(1, 5), 2),
+        ];
+        Map::new(MapConfig { size: 7, endgame, ..Default::default() }, players).unwrap()
+    }
+
+    #[test]
+    fn test_observation_contains_alive_opponents_and_bombs() {
+        //Arrange
+        let mut map = map(100);
+        map.players[2].kill(DeathCause::Bomb { owner: 0 }, Some(0));
+        map.bombs.push(Bomb::new(Coord::from(1, 1), 3, 0, 2));
+
+        //Act
+        let observation = Observation::from_map(&map, 3, 0).unwrap();
+
+        //Assert
+        assert_eq!(observation.me.position, Coord::from(1, 1));
+        assert_eq!(observation.me.active_bombs, 1);
+        assert_eq!(observation.opponents.iter().map(|player| player.id).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(observation.bombs, vec![ObservedBomb { position: Coord::from(1, 1), timer: 3, owner: 0, radius: 2 }]);
+        assert_eq!(observation.cell(Coord::from(0, 0)), CellType::Wall);
+        assert_eq!(observation.cell(Coord::from(5, 5)), CellType::Player);
+        assert_eq!(observation.cell(Coord::from(7, 1)), CellType::Wall);
+        assert_eq!(observation.turns_until_shrink, 97);
+        assert!(observation.next_shrink_cells.is_empty());
+    }
+
+    #[test]
+    fn test_observation_lists_upcoming_shrink_cells() {
+        //Arrange
+        let map = map(5);
+
+        //Act
+        let before = Observation::from_map(&map, 0, 0).unwrap();
+        let during = Observation::from_map(&map, 7, 0).unwrap();
+
+        //Assert
+        assert_eq!(before.max_turn, 5 + 25);
+        assert_eq!(before.turns_until_shrink, 5);
+        assert!(before.shrink_this_turn().is_empty());
+        assert_eq!(before.next_shrink_cells.len(), SHRINK_LOOKAHEAD - 5);
+        assert_eq!(before.next_shrink_cells[0], ObservedShrink { turn: 5, position: calculate_shrink_location(0, 7, 7).unwrap() });
+        assert_eq!(during.turns_until_shrink, 0);
+        assert_eq!(during.next_shrink_cells[0].position, calculate_shrink_location(2, 7, 7).unwrap());
+        assert_eq!(during.shrink_this_turn(), vec![calculate_shrink_location(2, 7, 7).unwrap()]);
+    }
+
+    #[test]
+    fn test_observation_lists_whole_rings_per_turn() {
+        //Arrange
+        let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0), Player::new("P2".to_string(), Coord::from(5, 5), 1)];
+        let map = Map::new(MapConfig { size: 7, endgame: 5, shrink: ShrinkPattern::Rings { interval: 3 }, ..Default::default() }, players).unwrap();
+
+        //Act
+        let observation = Observation::from_map(&map, 5, 0).unwrap();
+
+        //Assert
+        assert_eq!(observation.max_turn, 5 + 7);
+        assert_eq!(observation.shrink_this_turn().len(), 16);
+        assert!(observation.next_shrink_cells.iter().all(|shrink| [5, 8, 11].contains(&shrink.turn)));
+        assert_eq!(observation.next_shrink_cells.len(), 16 + 8 + 1);
+    }
+
+    #[test]
+    fn test_observation_round_trips_through_json() {
+        //Arrange
+        let observation = Observation::from_map(&map(100), 0, 1).unwrap();
+
+        //Act
+        let json = serde_json::to_string(&observation).unwrap();
+        let parsed: Observation = serde_json::from_str(&json).unwrap();
+
+        //Assert
+        assert!(json.contains(r#""position":{"col":5,"row":5}"#));
+        assert!(json.starts_with(r#"{"turn":0,"max_turn":125,"endgame":100,"width":7,"height":7,"grid":["W","W","#));
+        assert_eq!(parsed, observation);
+    }
+}
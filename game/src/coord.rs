@@ -1,11 +1,14 @@
 use std::fmt;
+use serde::{Deserialize, Serialize};
 
 use crate::map::enums::command::Command;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Row(pub usize);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
 pub struct Col(pub usize);
 
 impl Row {
@@ -46,7 +49,7 @@ impl fmt::Debug for Coord {
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Ord, PartialOrd, Serialize, Deserialize)]
 pub struct Coord {
     pub col: Col,
     pub row: Row,
@@ -99,7 +102,7 @@ impl Coord {
             Command::Down => self.move_down(),
             Command::Left => self.move_left(),
             Command::Right => self.move_right(),
-            Command::PlaceBomb => Some(*self),
+            Command::PlaceBomb | Command::KickBomb(_) | Command::Detonate => Some(*self),
             Command::Wait => Some(*self),
         }
     }
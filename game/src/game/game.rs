@@ -1,8 +1,22 @@
+use std::any::Any;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::time::Duration;
+use web_time::Instant;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
 use crate::bot::bot_data::BotData;
 use crate::coord::Coord;
 use crate::bot::bot::{BotController};
+use crate::bot::observation::Observation;
+use crate::game::game_error::GameError;
+use crate::game::rejected_bomb::RejectedBomb;
+use crate::map::enums::death_cause::DeathCause;
+use crate::map::enums::game_event::GameEvent;
+use crate::map::enums::game_outcome::GameOutcome;
 use crate::map::player::Player;
+use crate::map::structs::time_budget::TimeoutPolicy;
 use crate::map::structs::map_config::MapConfig;
+use crate::map::validators::map_validator::map_validator_chain_factory::MapValidatorChainFactory;
 use crate::{game::game_result::GameResult, map::enums::command::Command, map::map::Map};
 
 pub struct Game {
@@ -12,36 +26,54 @@ pub struct Game {
     pub max_turn: usize,
     pub player_actions: Vec<Vec<Command>>,
     pub debug_info: Vec<Vec<String>>,
+    time_used: Vec<Duration>,
+    /// Number of moves per player that went over the time budget.
+    pub timeouts: Vec<usize>,
+    /// Panic message per player whose bot panicked. Such a bot is disqualified for the rest of the game.
+    pub panics: Vec<Option<String>>,
+    /// Bomb placements refused because the player had reached its bomb limit.
+    pub rejected_bombs: Vec<RejectedBomb>,
+    /// What happened in every turn, only filled when `MapConfig::record_events` is set.
+    pub events: Vec<Vec<GameEvent>>,
 }
 
 impl Game {
+    /// Starts the bots and builds the map, or tells why `map_settings` cannot be played.
     pub fn build(
         mut bots: Vec<BotController>,
         map_settings: MapConfig,
         bot_data: Option<Vec<BotData>>,
-    ) -> Self {
-        for (i, bot) in bots.iter_mut().enumerate() {
-            bot.start_game(&map_settings, i);
-        }
-        let map_size = map_settings.size.clone();
+    ) -> Result<Self, GameError> {
+        // Checked first, so no bot starts and no spawn is laid out for settings that cannot be played
+        let player_count = bot_data.as_ref().map_or(bots.len(), Vec::len);
+        MapValidatorChainFactory::validate(&map_settings, player_count)?;
+        let mut rng = StdRng::seed_from_u64(map_settings.seed);
+        let panics: Vec<Option<String>> = bots
+            .iter_mut()
+            .enumerate()
+            .map(|(i, bot)| {
+                let bot_rng = StdRng::from_rng(&mut rng);
+                catch_unwind(AssertUnwindSafe(|| bot.start_game(&map_settings, i, bot_rng)))
+                    .err()
+                    .map(panic_message)
+            })
+            .collect();
+        let spawns = map_settings.spawn_positions();
         let map = Map::new(
             map_settings,
-            Self::generate_players_from_bots(&bots, bot_data, map_size),
-        );
-        Game::from_map(map, bots)
+            Self::generate_players_from_bots(&bots, bot_data, &spawns),
+        )?;
+        let mut game = Game::from_map(map, bots);
+        game.panics = panics;
+        Ok(game)
     }
 
     pub fn generate_players_from_bots(
-        bots: &Vec<BotController>,
+        bots: &[BotController],
         bot_data: Option<Vec<BotData>>,
-        size: usize,
+        spawns: &[Coord],
     ) -> Vec<Player> {
-        let positions = [
-            Coord::from(1, 1),
-            Coord::from(1, size - 2),
-            Coord::from(size - 2, 1),
-            Coord::from(size - 2, size - 2),
-        ];
+        let positions = spawns.iter().copied();
 
         match bot_data {
             Some(bot_data) => bot_data
@@ -59,77 +91,184 @@ impl Game {
 
     pub fn from_map(map: Map, bots: Vec<BotController>) -> Self {
         let player_count = bots.len();
-        let inner_size = map.map_settings.size - 2;
-        let max_turn = map.map_settings.endgame + (inner_size * inner_size);
+        let max_turn = map.max_turn();
         Game {
             map,
             bots,
             turn: 0,
-            max_turn: max_turn,
+            max_turn,
             player_actions: vec![Vec::new(); player_count],
             debug_info: vec![Vec::new(); player_count],
+            time_used: vec![Duration::ZERO; player_count],
+            timeouts: vec![0; player_count],
+            panics: vec![None; player_count],
+            rejected_bombs: Vec::new(),
+            events: Vec::new(),
         }
     }
 
-    pub fn run(&mut self) -> GameResult {
+    pub fn run(&mut self) -> Result<GameResult, GameError> {
         self.run_game(None)
     }
 
-    pub fn run_game(&mut self, commands: Option<&Vec<Vec<Command>>>) -> GameResult {
-        while !self.map.has_winner() {
-            self.run_round(commands);
+    pub fn run_game(&mut self, commands: Option<&Vec<Vec<Command>>>) -> Result<GameResult, GameError> {
+        while !self.map.is_game_over() {
+            self.run_round(commands)?;
         }
-        GameResult::build(self)
+        Ok(GameResult::build(self))
     }
 
     pub fn winner_name(&self) -> String {
-        match &self.map.winner {
-            Some(player) => player.name.clone(),
-            None => "No winner yet".to_string(),
+        match self.map.outcome() {
+            GameOutcome::Winner(id) => self.map.get_player(id).map_or(String::new(), |player| player.name.clone()),
+            GameOutcome::Draw => "Draw".to_string(),
+            GameOutcome::InProgress => "No winner yet".to_string(),
         }
     }
-    pub fn run_round(&mut self, replay_commands: Option<&Vec<Vec<Command>>>) {
+    pub fn run_round(&mut self, replay_commands: Option<&Vec<Vec<Command>>>) -> Result<(), GameError> {
         if self.turn >= self.max_turn {
-            panic!("Something went terribly wrong ")
+            return Err(GameError::TurnLimitReached { max_turn: self.max_turn });
         }
-        for player_id in self.map.get_alive_players_ids() {
-            let command = if let Some(replay) = replay_commands {
-                replay[player_id][self.turn]
-            } else {
-                self.get_command(player_id)
-            };
-            self.map.try_execute_command(player_id, command);
+        if self.map.is_game_over() {
+            return Err(GameError::GameOver { turn: self.turn });
         }
+        if self.map.map_settings.move_resolution.is_simultaneous() {
+            // Every bot decides before anything changes, so all of them see the same map
+            let mut commands = Vec::new();
+            for player_id in self.map.get_alive_players_ids() {
+                match self.next_command(player_id, replay_commands)? {
+                    Some(command) => commands.push((player_id, command)),
+                    None => self.map.forfeit_player(player_id, self.forfeit_cause(player_id)),
+                }
+            }
+            self.map.execute_simultaneously(commands);
+        } else {
+            for player_id in self.map.get_alive_players_ids() {
+                match self.next_command(player_id, replay_commands)? {
+                    Some(command) => self.map.try_execute_command(player_id, command),
+                    // A bot without a command for this turn has forfeited
+                    None => self.map.forfeit_player(player_id, self.forfeit_cause(player_id)),
+                }
+            }
+        }
+        let turn = self.turn;
+        self.rejected_bombs.extend(
+            self.map.rejected_bombs.drain(..).map(|(player_id, position)| RejectedBomb { turn, player_id, position }),
+        );
         self.map.process_bombs();
         if self.map.map_settings.endgame <= self.turn {
             self.map.handle_shrink(self.turn);
         }
+        self.map.record_deaths(self.turn);
+        if self.map.map_settings.record_events {
+            self.events.push(self.map.events.drain(..).collect());
+        }
+        self.map.update_outcome();
         self.turn += 1;
+        // Only happens when the map does not shrink all the way
+        if self.turn >= self.max_turn && !self.map.is_game_over() {
+            self.map.outcome = GameOutcome::Draw;
+        }
+        Ok(())
+    }
+
+
+    /// The command of the player for this turn, from the replay when there is one. `None` when the
+    /// player forfeits.
+    fn next_command(&mut self, player_id: usize, replay_commands: Option<&Vec<Vec<Command>>>) -> Result<Option<Command>, GameError> {
+        match replay_commands {
+            Some(replay) => {
+                let commands = replay.get(player_id).ok_or(GameError::MissingReplayCommands { player_id })?;
+                Ok(commands.get(self.turn).copied())
+            }
+            None => self.get_command(player_id),
+        }
     }
 
+    /// Why a player without a command leaves the game. A replay does not know, so it forfeits.
+    fn forfeit_cause(&self, player_id: usize) -> DeathCause {
+        if self.panics.get(player_id).is_some_and(|panic| panic.is_some()) {
+            DeathCause::Disqualified
+        } else if self.timeouts.get(player_id).is_some_and(|&timeouts| timeouts > 0) {
+            DeathCause::Timeout
+        } else {
+            DeathCause::Forfeit
+        }
+    }
+
+    /// Asks the bot for its move within the time budget. Returns `None` when the bot forfeits,
+    /// which also happens when the bot panics.
+    fn get_command(&mut self, player_id: usize) -> Result<Option<Command>, GameError> {
+        if self.bots.get(player_id).is_none() {
+            return Err(GameError::BotNotFound { player_id });
+        }
+        if self.panics[player_id].is_some() {
+            return Ok(None);
+        }
+        let budget = self.map.map_settings.time_budget.clone();
+        if budget.game_exceeded(self.time_used[player_id]) {
+            self.timeouts[player_id] += 1;
+            return Ok(self.record_command(player_id, Command::Wait, String::new()));
+        }
+
+        let observation = Observation::from_map(&self.map, self.turn, player_id)?;
+        let bot = &mut self.bots[player_id];
 
-    fn get_command(&mut self, player_id: usize) -> Command{
-        let bot = self
-            .bots
-            .get_mut(player_id)
-            .expect("Bot not found for player index");
-        let loc = self.map.get_player(player_id).unwrap().position;
+        // Only measured afterwards, a bot that never returns blocks here, see `TimeBudget`
+        let start = Instant::now();
+        let result = catch_unwind(AssertUnwindSafe(|| (bot.get_move(&observation), bot.get_debug_info())));
+        let elapsed = start.elapsed();
+        self.time_used[player_id] += elapsed;
 
-        let new_command = bot.get_move(&self.map, loc);
-        self.player_actions[player_id].push(new_command);
-        self.debug_info[player_id].push(bot.get_debug_info());
-        new_command
+        let (new_command, debug_info) = match result {
+            Ok(result) => result,
+            Err(payload) => {
+                self.panics[player_id] = Some(panic_message(payload));
+                return Ok(None);
+            }
+        };
+
+        if budget.move_exceeded(elapsed) || budget.game_exceeded(self.time_used[player_id]) {
+            self.timeouts[player_id] += 1;
+            return Ok(match budget.on_timeout {
+                TimeoutPolicy::Wait => self.record_command(player_id, Command::Wait, debug_info),
+                TimeoutPolicy::Forfeit => None,
+            });
+        }
+        Ok(self.record_command(player_id, new_command, debug_info))
+    }
+
+    fn record_command(&mut self, player_id: usize, command: Command, debug_info: String) -> Option<Command> {
+        self.player_actions[player_id].push(command);
+        self.debug_info[player_id].push(debug_info);
+        Some(command)
     }
 }
 
 
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::bot::bot::{BotController, Bot};
     use crate::map::structs::map_config::MapConfig;
     use crate::map::enums::command::Command;
-    use crate::coord::Coord;
+    use crate::map::enums::direction::Direction;
+    use crate::map::enums::map_config_error::MapConfigError;
+    use crate::map::enums::move_resolution::MoveResolution;
+    use crate::map::structs::map_layout::MapLayout;
+    use crate::map::enums::shrink_pattern::ShrinkPattern;
+    use rand::rngs::StdRng;
+    use crate::map::structs::time_budget::TimeBudget;
 
     struct DummyBot {
         name: String,
@@ -142,16 +281,51 @@ mod tests {
     }
 
     impl Bot for DummyBot {
-        fn start_game(&mut self, _map_settings: &MapConfig, bot_name: String, _bot_id: usize) -> bool {
+        fn start_game(&mut self, _map_settings: &MapConfig, bot_name: String, _bot_id: usize, _rng: StdRng) -> bool {
             self.name = bot_name;
             true
         }
 
-        fn get_move(&mut self, _map: &Map, _player_location: Coord) -> Command {
+        fn get_move(&mut self, _observation: &Observation) -> Command {
             Command::Wait
         }
     }
 
+    struct SlowBot;
+
+    impl Bot for SlowBot {
+        fn start_game(&mut self, _map_settings: &MapConfig, _bot_name: String, _bot_id: usize, _rng: StdRng) -> bool {
+            true
+        }
+
+        fn get_move(&mut self, _observation: &Observation) -> Command {
+            std::thread::sleep(Duration::from_millis(5));
+            Command::Left
+        }
+    }
+
+    struct PanicBot;
+
+    impl Bot for PanicBot {
+        fn start_game(&mut self, _map_settings: &MapConfig, _bot_name: String, _bot_id: usize, _rng: StdRng) -> bool {
+            true
+        }
+
+        fn get_move(&mut self, _observation: &Observation) -> Command {
+            panic!("bot exploded")
+        }
+    }
+
+    fn slow_game(on_timeout: TimeoutPolicy) -> Game {
+        let time_budget = TimeBudget { per_move: Some(Duration::from_millis(1)), per_game: None, on_timeout };
+        let map_settings = MapConfig { size: 7, time_budget, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+            BotController::new(Box::new(SlowBot), "slow".to_string()),
+        ];
+        Game::build(bots, map_settings, None).unwrap()
+    }
+
     #[test]
     fn test_run_round_records_actions_and_increments_turn() {
         //Arrange
@@ -160,10 +334,10 @@ mod tests {
             BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
             BotController::new(Box::new(DummyBot::new()), "bot2".to_string()),
         ];
-        let mut game = Game::build(bots, map_settings, None);
+        let mut game = Game::build(bots, map_settings, None).unwrap();
         
         //Act
-        game.run_round(None);
+        game.run_round(None).unwrap();
 
         //Assert
         let alive_ids = game.map.get_alive_players_ids();
@@ -176,15 +350,228 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
-    fn test_run_round_panics_when_turn_too_large() {
+    fn test_sudden_death_without_shrink_is_a_draw_at_max_turn() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, endgame: 5, shrink: ShrinkPattern::None, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+            BotController::new(Box::new(DummyBot::new()), "bot2".to_string()),
+        ];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        game.run().unwrap();
+
+        //Assert
+        assert_eq!(game.turn, game.max_turn);
+        assert_eq!(game.map.outcome(), GameOutcome::Draw);
+        assert_eq!(game.map.get_alive_players_ids().len(), 2);
+    }
+
+    #[test]
+    fn test_run_round_fails_when_turn_too_large() {
         //Arrange
         let map_settings = MapConfig { size: 7, ..Default::default() };
         let bots = vec![BotController::new(Box::new(DummyBot::new()), "bot1".to_string())];
-        let mut game = Game::build(bots, map_settings, None);
+        let mut game = Game::build(bots, map_settings, None).unwrap();
         game.turn = game.max_turn;
 
         //Act
-        game.run_round(None);
+        let result = game.run_round(None);
+
+        //Assert
+        assert_eq!(result, Err(GameError::TurnLimitReached { max_turn: game.max_turn }));
+    }
+
+    #[test]
+    fn test_run_round_fails_when_game_is_over() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+            BotController::new(Box::new(PanicBot), "panic".to_string()),
+        ];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+        game.run_round(None).unwrap();
+        let positions: Vec<_> = game.map.players.iter().map(|player| player.position).collect();
+
+        //Act
+        let result = game.run_round(None);
+
+        //Assert
+        assert_eq!(result, Err(GameError::GameOver { turn: 1 }));
+        assert_eq!(game.turn, 1);
+        assert_eq!(game.map.players.iter().map(|player| player.position).collect::<Vec<_>>(), positions);
+    }
+
+    #[test]
+    fn test_simultaneous_resolution_removes_the_advantage_of_moving_first() {
+        //Arrange
+        let layout = MapLayout::parse("WWWWWWW\nW1 2  W\nW     W\nW     W\nW     W\nW     W\nWWWWWWW").unwrap();
+        let commands = vec![vec![Command::Right], vec![Command::Left]];
+        let play = |move_resolution| {
+            let map_settings = MapConfig { width: Some(7), height: Some(7), layout: Some(layout.clone()), move_resolution, ..Default::default() };
+            let bot_data = vec![BotData { id: 0, name: "bot1".to_string() }, BotData { id: 1, name: "bot2".to_string() }];
+            let mut game = Game::build(Vec::new(), map_settings, Some(bot_data)).unwrap();
+            game.run_round(Some(&commands)).unwrap();
+            game.map.players.iter().map(|player| player.position).collect::<Vec<_>>()
+        };
+
+        //Act
+        let sequential = play(MoveResolution::Sequential);
+        let simultaneous = play(MoveResolution::SimultaneousBounce);
+
+        //Assert
+        assert_eq!(sequential, vec![Coord::from(2, 1), Coord::from(3, 1)]);
+        assert_eq!(simultaneous, vec![Coord::from(1, 1), Coord::from(3, 1)]);
+    }
+
+    #[test]
+    fn test_events_are_recorded_per_turn_when_enabled() {
+        //Arrange
+        let layout = MapLayout::parse("WWWWWWW\nW1 2  W\nW     W\nW     W\nW     W\nW     W\nWWWWWWW").unwrap();
+        let commands = vec![vec![Command::Right, Command::PlaceBomb], vec![Command::Left, Command::Wait]];
+        let play = |record_events| {
+            let map_settings = MapConfig { width: Some(7), height: Some(7), layout: Some(layout.clone()), record_events, ..Default::default() };
+            let bot_data = vec![BotData { id: 0, name: "bot1".to_string() }, BotData { id: 1, name: "bot2".to_string() }];
+            let mut game = Game::build(Vec::new(), map_settings, Some(bot_data)).unwrap();
+            game.run_round(Some(&commands)).unwrap();
+            game.run_round(Some(&commands)).unwrap();
+            game.events
+        };
+
+        //Act
+        let recorded = play(true);
+        let not_recorded = play(false);
+
+        //Assert
+        assert_eq!(recorded, vec![
+            vec![
+                GameEvent::Moved { player: 0, from: Coord::from(1, 1), to: Coord::from(2, 1) },
+                GameEvent::MoveBlocked { player: 1, position: Coord::from(3, 1), direction: Direction::Left },
+            ],
+            vec![GameEvent::BombPlaced { player: 0, position: Coord::from(2, 1) }],
+        ]);
+        assert!(not_recorded.is_empty());
+    }
+
+    #[test]
+    fn test_build_reports_invalid_settings() {
+        //Arrange
+        let map_settings = MapConfig { size: 8, ..Default::default() };
+        let bots = vec![BotController::new(Box::new(DummyBot::new()), "bot1".to_string())];
+
+        //Act
+        let result = Game::build(bots, map_settings, None);
+
+        //Assert
+        assert!(matches!(result, Err(GameError::InvalidConfig(MapConfigError::InvalidSize { width: 8, height: 8 }))));
+    }
+
+    #[test]
+    fn test_build_reports_too_small_maps() {
+        for size in [0, 1] {
+            //Arrange
+            let map_settings = MapConfig { size, ..Default::default() };
+            let bots = vec![
+                BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+                BotController::new(Box::new(DummyBot::new()), "bot2".to_string()),
+            ];
+
+            //Act
+            let result = Game::build(bots, map_settings, None);
+
+            //Assert
+            assert!(matches!(result, Err(GameError::InvalidConfig(MapConfigError::InvalidSize { .. }))));
+        }
+    }
+
+    #[test]
+    fn test_replay_without_commands_for_a_player_fails() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+            BotController::new(Box::new(DummyBot::new()), "bot2".to_string()),
+        ];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        let result = game.run_round(Some(&vec![vec![Command::Wait]]));
+
+        //Assert
+        assert_eq!(result, Err(GameError::MissingReplayCommands { player_id: 1 }));
+    }
+
+    #[test]
+    fn test_slow_move_becomes_wait() {
+        //Arrange
+        let mut game = slow_game(TimeoutPolicy::Wait);
+
+        //Act
+        game.run_round(None).unwrap();
+
+        //Assert
+        assert!(matches!(game.player_actions[1][0], Command::Wait));
+        assert_eq!(game.timeouts[1], 1);
+        assert!(game.map.get_player(1).unwrap().is_alive());
+    }
+
+    #[test]
+    fn test_slow_move_forfeits_and_replays() {
+        //Arrange
+        let mut game = slow_game(TimeoutPolicy::Forfeit);
+
+        //Act
+        let result = game.run().unwrap();
+
+        //Assert
+        assert_eq!(result.winner, "bot1 (0)");
+        assert_eq!(result.players[1].death_cause, Some(DeathCause::Timeout));
+        assert_eq!(result.players[1].timeouts, 1);
+        assert!(result.replay_data[1].is_empty());
+
+        let mut replay = Game::build(Vec::new(), result.game_settings.clone(), Some(result.bots.clone())).unwrap();
+        assert_eq!(replay.run_game(Some(&result.replay_data)).unwrap().winner, "bot1 (0)");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_used_up_game_budget_counts_every_skipped_move_as_timeout() {
+        //Arrange
+        let time_budget = TimeBudget { per_move: None, per_game: Some(Duration::from_millis(1)), on_timeout: TimeoutPolicy::Wait };
+        let map_settings = MapConfig { size: 7, time_budget, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+            BotController::new(Box::new(SlowBot), "slow".to_string()),
+        ];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        game.run_round(None).unwrap();
+        game.run_round(None).unwrap();
+
+        //Assert
+        assert_eq!(game.timeouts[1], 2);
+        assert!(game.player_actions[1].iter().all(|command| matches!(command, Command::Wait)));
+    }
+
+    #[test]
+    fn test_panicking_bot_is_disqualified() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(DummyBot::new()), "bot1".to_string()),
+            BotController::new(Box::new(PanicBot), "panic".to_string()),
+        ];
+        let mut game = Game::build(bots, map_settings, None).unwrap();
+
+        //Act
+        let result = game.run().unwrap();
+
+        //Assert
+        assert_eq!(result.winner, "bot1 (0)");
+        assert_eq!(result.players[1].panic, Some("bot exploded".to_string()));
+        assert_eq!(result.players[1].death_cause, Some(DeathCause::Disqualified));
+        assert_eq!(result.players[0].panic, None);
+    }
+}
@@ -0,0 +1,47 @@
+use std::fmt;
+use crate::map::enums::map_config_error::MapConfigError;
+
+/// Why the engine could not build or advance a game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameError {
+    /// The map settings cannot be played, see `MapConfigError`.
+    InvalidConfig(MapConfigError),
+    /// A round was requested after the game reached its last turn.
+    TurnLimitReached { max_turn: usize },
+    /// A round was requested after the game already had a winner or ended in a draw.
+    GameOver { turn: usize },
+    /// There is no bot controlling this player.
+    BotNotFound { player_id: usize },
+    /// There is no player with this id on the map.
+    PlayerNotFound { player_id: usize },
+    /// A replay has no commands for this player.
+    MissingReplayCommands { player_id: usize },
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::InvalidConfig(err) => write!(f, "Invalid map settings: {err}"),
+            GameError::TurnLimitReached { max_turn } => write!(f, "The game already reached its last turn {max_turn}"),
+            GameError::GameOver { turn } => write!(f, "The game is already over at turn {turn}"),
+            GameError::BotNotFound { player_id } => write!(f, "No bot found for player {player_id}"),
+            GameError::PlayerNotFound { player_id } => write!(f, "No player {player_id} on the map"),
+            GameError::MissingReplayCommands { player_id } => write!(f, "The replay has no commands for player {player_id}"),
+        }
+    }
+}
+
+impl std::error::Error for GameError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            GameError::InvalidConfig(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<MapConfigError> for GameError {
+    fn from(err: MapConfigError) -> Self {
+        GameError::InvalidConfig(err)
+    }
+}
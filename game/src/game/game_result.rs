@@ -2,20 +2,43 @@ use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 use crate::bot::bot_data::BotData;
+use crate::map::structs::kill_record::KillRecord;
 use crate::map::structs::map_config::MapConfig;
 use crate::game::game::Game;
+use crate::game::player_result::PlayerResult;
+use crate::game::rejected_bomb::RejectedBomb;
 use crate::map::enums::command::Command;
+use crate::map::enums::death_cause::DeathCause;
+use crate::map::enums::game_event::GameEvent;
+use crate::map::enums::game_outcome::GameOutcome;
 
 /// Represents the result of a game.
 #[derive(Clone, Serialize, Deserialize)]
 pub struct GameResult {
     pub winner: String,
+    #[serde(default)]
+    pub outcome: GameOutcome,
+    /// Packed when serialized, see `packed_commands`.
+    #[serde(with = "crate::game::packed_commands")]
     pub replay_data: Vec<Vec<Command>>,
     pub debug_data: Vec<Vec<String>>,
     pub game_settings: MapConfig,
     pub rounds: usize,
     pub score: usize,
     pub bots: Vec<BotData>,
+    #[serde(default)]
+    pub seed: u64,
+    /// Standings of all players, in player id order.
+    #[serde(default)]
+    pub players: Vec<PlayerResult>,
+    #[serde(default)]
+    pub rejected_bombs: Vec<RejectedBomb>,
+    /// Every death of the game with who got the credit, in order of death.
+    #[serde(default)]
+    pub kills: Vec<KillRecord>,
+    /// Events of every turn, empty unless `MapConfig::record_events` was set.
+    #[serde(default)]
+    pub events: Vec<Vec<GameEvent>>,
 }
 
 impl GameResult {
@@ -23,15 +46,26 @@ impl GameResult {
         let winner = game.winner_name();
         let game_settings = game.map.map_settings.clone();
         let bot_data = game.map.players.iter().map(|player| BotData {id: player.id, name: player.name.clone()}).collect();
+        let mut players = PlayerResult::from_players(&game.map.players);
+        for player in players.iter_mut() {
+            player.timeouts = game.timeouts.get(player.id).copied().unwrap_or(0);
+            player.panic = game.panics.get(player.id).cloned().flatten();
+        }
 
         GameResult {
             winner,
+            outcome: game.map.outcome(),
             replay_data: game.player_actions.clone(),
             debug_data: game.debug_info.clone(),
             game_settings,
             rounds: game.turn,
             score: GameResult::calculate_score(game),
-            bots: bot_data
+            bots: bot_data,
+            seed: game.map.map_settings.seed,
+            players,
+            rejected_bombs: game.rejected_bombs.clone(),
+            kills: game.map.kills.clone(),
+            events: game.events.clone(),
         }
     }
 
@@ -40,19 +74,17 @@ impl GameResult {
         let mut killers: HashMap<usize, usize> = HashMap::new();
 
         for player in &game.map.players {
-            score += game.map.map_settings.size * match player.reason_killed.as_str() {
-                "suicide" => 10,
-                "bomb" => {
+            score += (game.map.map_settings.width() + game.map.map_settings.height()) / 2 * match player.death_cause {
+                Some(DeathCause::Suicide) => 10,
+                Some(DeathCause::Bomb { .. }) => {
                     let mut bomb_score = 15;
-                    if let Some(kill_count) = killers.get_mut(&player.killed_by) {
-                        *kill_count += 1;
+                    if let Some(killer) = player.killed_by {
+                        let kill_count = killers.entry(killer).and_modify(|count| *count += 1).or_insert(0);
                         bomb_score += 5 * *kill_count;
-                    } else {
-                        killers.insert(player.killed_by, 0);
                     }
                     bomb_score
                 }
-                "shrink" => 3,
+                Some(DeathCause::Shrink) => 3,
                 _ => 0,
             };
         }
@@ -1,4 +1,11 @@
+pub mod game_error;
 pub mod game_result;
+pub mod packed_commands;
+pub mod player_result;
+pub mod rejected_bomb;
+pub mod replay_file;
+pub mod replay_file_error;
+pub mod replay_format;
 pub mod game;
 pub mod replay_engine;
 
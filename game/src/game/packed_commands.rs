@@ -0,0 +1,391 @@
+//! Compact encoding of the commands of a game, as used for `replay_data` and replay files.
+//!
+//! Every player gets its own byte string: the number of commands as LEB128, followed by 3-bit codes
+//! starting at the lowest bit. Moves, `PlaceBomb` and a single `Wait` have a code of their own. A
+//! streak of waits is one code followed by its length, and the rare commands use an escape code
+//! with a second 3-bit code. Text formats get the bytes as base64.
+//!
+//! Reading also accepts the plain list of commands, so older results and replays still load.
+
+use std::fmt;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::Deserialize;
+use crate::map::enums::command::Command;
+use crate::map::enums::direction::Direction;
+
+const UP: u8 = 0;
+const DOWN: u8 = 1;
+const LEFT: u8 = 2;
+const RIGHT: u8 = 3;
+const PLACE_BOMB: u8 = 4;
+const WAIT: u8 = 5;
+/// Followed by the length of the streak in 7-bit groups, 6 bits of length and a continuation bit.
+const WAIT_STREAK: u8 = 6;
+/// Followed by a second code: a kick in the direction of the move codes, or a detonate.
+const ESCAPE: u8 = 7;
+const DETONATE: u8 = 4;
+/// Shorter streaks are cheaper as single waits.
+const MIN_WAIT_STREAK: usize = 4;
+/// Most commands one player can have. Far more turns than a game lasts, but it keeps a crafted
+/// count with a long wait streak from allocating gigabytes.
+pub const MAX_COMMANDS: usize = 1 << 20;
+/// Most commands reserved up front for a plain list, like serde's cautious size hint, so a
+/// crafted length cannot make us allocate more than a megabyte before reading anything.
+const MAX_PREALLOCATED_COMMANDS: usize = 1024 * 1024 / size_of::<Command>();
+
+pub fn pack(commands: &[Command]) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    write_leb128(&mut bytes, commands.len());
+    let mut bits = BitWriter { bytes, used: 0 };
+
+    let mut index = 0;
+    while index < commands.len() {
+        let streak = commands[index..].iter().take_while(|command| **command == Command::Wait).count();
+        if streak >= MIN_WAIT_STREAK {
+            bits.write(WAIT_STREAK, 3);
+            let mut length = streak;
+            loop {
+                let more = length >= 64;
+                bits.write((length & 63) as u8 | if more { 64 } else { 0 }, 7);
+                length >>= 6;
+                if !more {
+                    break;
+                }
+            }
+            index += streak;
+            continue;
+        }
+        match commands[index] {
+            Command::Up => bits.write(UP, 3),
+            Command::Down => bits.write(DOWN, 3),
+            Command::Left => bits.write(LEFT, 3),
+            Command::Right => bits.write(RIGHT, 3),
+            Command::PlaceBomb => bits.write(PLACE_BOMB, 3),
+            Command::Wait => bits.write(WAIT, 3),
+            Command::KickBomb(direction) => {
+                bits.write(ESCAPE, 3);
+                bits.write(direction_code(direction), 3);
+            }
+            Command::Detonate => {
+                bits.write(ESCAPE, 3);
+                bits.write(DETONATE, 3);
+            }
+        }
+        index += 1;
+    }
+    bits.bytes
+}
+
+pub fn unpack(bytes: &[u8]) -> Result<Vec<Command>, String> {
+    let (count, start) = read_leb128(bytes).ok_or("Packed commands have no length")?;
+    if count > MAX_COMMANDS {
+        return Err(format!("Packed commands claim {count} commands, at most {MAX_COMMANDS} are allowed"));
+    }
+    let mut bits = BitReader { bytes: &bytes[start..], position: 0 };
+    // The count comes from the file, reserve no more than the remaining bits can hold
+    let mut commands = Vec::with_capacity(count.min(bits.bytes.len() * 8 / 3));
+
+    while commands.len() < count {
+        let code = bits.read(3).ok_or("Packed commands end too early")?;
+        match code {
+            UP => commands.push(Command::Up),
+            DOWN => commands.push(Command::Down),
+            LEFT => commands.push(Command::Left),
+            RIGHT => commands.push(Command::Right),
+            PLACE_BOMB => commands.push(Command::PlaceBomb),
+            WAIT => commands.push(Command::Wait),
+            WAIT_STREAK => {
+                let mut length = 0;
+                let mut shift = 0;
+                loop {
+                    let group = bits.read(7).ok_or("Packed commands end inside a wait streak")?;
+                    length |= usize::from(group & 63).checked_shl(shift).ok_or("Wait streak is too long")?;
+                    shift += 6;
+                    if group & 64 == 0 {
+                        break;
+                    }
+                }
+                if length > count - commands.len() {
+                    return Err("Wait streak runs past the last command".to_string());
+                }
+                commands.extend(std::iter::repeat_n(Command::Wait, length));
+            }
+            _ => match bits.read(3).ok_or("Packed commands end after an escape")? {
+                DETONATE => commands.push(Command::Detonate),
+                code => commands.push(Command::KickBomb(code_direction(code).ok_or("Unknown escaped command")?)),
+            },
+        }
+    }
+    Ok(commands)
+}
+
+/// Serializes the commands of every player packed.
+pub fn serialize<S: Serializer>(commands: &[Vec<Command>], serializer: S) -> Result<S::Ok, S::Error> {
+    let human_readable = serializer.is_human_readable();
+    let mut seq = serializer.serialize_seq(Some(commands.len()))?;
+    for player_commands in commands {
+        let bytes = pack(player_commands);
+        if human_readable {
+            seq.serialize_element(&STANDARD.encode(bytes))?;
+        } else {
+            seq.serialize_element(&serde_bytes_ref(&bytes))?;
+        }
+    }
+    seq.end()
+}
+
+/// Reads packed commands as well as the plain lists older versions wrote.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Vec<Command>>, D::Error> {
+    let players: Vec<PlayerCommands> = Vec::deserialize(deserializer)?;
+    Ok(players.into_iter().map(|player| player.0).collect())
+}
+
+/// The commands of one player in any of the accepted forms.
+struct PlayerCommands(Vec<Command>);
+
+impl<'de> Deserialize<'de> for PlayerCommands {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(PlayerCommandsVisitor).map(PlayerCommands)
+    }
+}
+
+struct PlayerCommandsVisitor;
+
+impl<'de> Visitor<'de> for PlayerCommandsVisitor {
+    type Value = Vec<Command>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("packed commands or a list of commands")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        let bytes = STANDARD.decode(value).map_err(E::custom)?;
+        unpack(&bytes).map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Self::Value, E> {
+        unpack(value).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut commands = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATED_COMMANDS));
+        while let Some(command) = seq.next_element()? {
+            if commands.len() == MAX_COMMANDS {
+                return Err(de::Error::custom(format!("More than {MAX_COMMANDS} commands for one player")));
+            }
+            commands.push(command);
+        }
+        Ok(commands)
+    }
+}
+
+/// Serializes as a byte string instead of a list of numbers.
+fn serde_bytes_ref(bytes: &[u8]) -> impl serde::Serialize + '_ {
+    struct Bytes<'a>(&'a [u8]);
+    impl serde::Serialize for Bytes<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_bytes(self.0)
+        }
+    }
+    Bytes(bytes)
+}
+
+fn direction_code(direction: Direction) -> u8 {
+    match direction {
+        Direction::Up => UP,
+        Direction::Down => DOWN,
+        Direction::Left => LEFT,
+        Direction::Right => RIGHT,
+    }
+}
+
+fn code_direction(code: u8) -> Option<Direction> {
+    match code {
+        UP => Some(Direction::Up),
+        DOWN => Some(Direction::Down),
+        LEFT => Some(Direction::Left),
+        RIGHT => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+fn write_leb128(bytes: &mut Vec<u8>, mut value: usize) {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            bytes.push(byte);
+            return;
+        }
+        bytes.push(byte | 0x80);
+    }
+}
+
+/// The value and the number of bytes it took.
+fn read_leb128(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut value: usize = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        value |= usize::from(byte & 0x7f).checked_shl(7 * index as u32)?;
+        if byte & 0x80 == 0 {
+            return Some((value, index + 1));
+        }
+    }
+    None
+}
+
+struct BitWriter {
+    bytes: Vec<u8>,
+    /// Bits used of the last byte, 0 when a new byte is needed.
+    used: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u8, bits: u32) {
+        for bit in 0..bits {
+            if self.used == 0 {
+                self.bytes.push(0);
+            }
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= ((value >> bit) & 1) << self.used;
+            self.used = (self.used + 1) % 8;
+        }
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl BitReader<'_> {
+    fn read(&mut self, bits: u32) -> Option<u8> {
+        let mut value = 0;
+        for bit in 0..bits {
+            let byte = self.bytes.get(self.position / 8)?;
+            value |= ((byte >> (self.position % 8)) & 1) << bit;
+            self.position += 1;
+        }
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize, Deserialize)]
+    struct Replay {
+        #[serde(with = "super")]
+        commands: Vec<Vec<Command>>,
+    }
+
+    fn every_command() -> Vec<Command> {
+        let mut commands = vec![Command::Up, Command::Down, Command::Left, Command::Right, Command::PlaceBomb, Command::Detonate];
+        commands.extend([Direction::Up, Direction::Down, Direction::Left, Direction::Right].map(Command::KickBomb));
+        commands.extend([Command::Wait; 3]);
+        commands.push(Command::Up);
+        commands.extend([Command::Wait; 500]);
+        commands
+    }
+
+    #[test]
+    fn test_pack_round_trips_every_command() {
+        //Arrange
+        let commands = every_command();
+
+        //Act
+        let packed = pack(&commands);
+
+        //Assert
+        assert_eq!(unpack(&packed), Ok(commands));
+        assert_eq!(unpack(&pack(&[])), Ok(vec![]));
+        // 2 bytes of length, 9 plain codes, 5 escaped commands and a streak code with 500 in 2 groups
+        assert_eq!(packed.len(), 2 + (9 * 3 + 5 * 6 + 3 + 2 * 7_usize).div_ceil(8));
+    }
+
+    #[test]
+    fn test_unpack_rejects_truncated_data() {
+        //Arrange
+        let packed = pack(&every_command());
+
+        //Act & Assert
+        assert!(unpack(&packed[..packed.len() - 1]).is_err());
+        assert!(unpack(&[]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_rejects_malformed_data() {
+        //Arrange
+        let mut huge_count = vec![0xff; 8];
+        huge_count.push(0x7f);
+        let mut overflowing_streak = BitWriter { bytes: vec![5], used: 0 };
+        overflowing_streak.write(UP, 3);
+        overflowing_streak.write(WAIT_STREAK, 3);
+        for _ in 0..10 {
+            overflowing_streak.write(63 | 64, 7);
+        }
+        overflowing_streak.write(15, 7);
+
+        //Act & Assert
+        assert!(unpack(&huge_count).is_err());
+        assert!(unpack(&overflowing_streak.bytes).is_err());
+        assert!(unpack(&[2, ESCAPE | (6 << 3)]).is_err());
+    }
+
+    #[test]
+    fn test_unpack_rejects_a_huge_count_with_a_huge_streak() {
+        //Arrange: claims a trillion commands and fills them with one wait streak
+        let count = 1usize << 40;
+        let mut bytes = Vec::new();
+        write_leb128(&mut bytes, count);
+        let mut malicious = BitWriter { bytes, used: 0 };
+        malicious.write(WAIT_STREAK, 3);
+        let mut length = count;
+        while length >= 64 {
+            malicious.write((length & 63) as u8 | 64, 7);
+            length >>= 6;
+        }
+        malicious.write(length as u8, 7);
+
+        //Act
+        let result = unpack(&malicious.bytes);
+
+        //Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_json_is_packed_and_old_lists_still_load() {
+        //Arrange
+        let replay = Replay { commands: vec![every_command(), vec![Command::Wait, Command::PlaceBomb]] };
+        let old_json = r#"{"commands":[["Up","PlaceBomb",{"KickBomb":"Left"}],[]]}"#;
+
+        //Act
+        let json = serde_json::to_string(&replay).unwrap();
+        let read: Replay = serde_json::from_str(&json).unwrap();
+        let old: Replay = serde_json::from_str(old_json).unwrap();
+
+        //Assert
+        assert!(json.starts_with(r#"{"commands":[""#));
+        assert_eq!(read.commands, replay.commands);
+        assert_eq!(old.commands, vec![vec![Command::Up, Command::PlaceBomb, Command::KickBomb(Direction::Left)], vec![]]);
+    }
+
+    #[test]
+    fn test_binary_formats_get_raw_bytes() {
+        //Arrange
+        let replay = Replay { commands: vec![every_command()] };
+
+        //Act
+        let bytes = rmp_serde::to_vec_named(&replay).unwrap();
+        let read: Replay = rmp_serde::from_slice(&bytes).unwrap();
+
+        //Assert
+        assert_eq!(read.commands, replay.commands);
+        assert!(bytes.len() < 40);
+    }
+}
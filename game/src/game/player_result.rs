@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use crate::map::enums::death_cause::DeathCause;
+use crate::map::player::Player;
+
+/// How a single player finished a game.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(from = "StoredPlayerResult")]
+pub struct PlayerResult {
+    pub id: usize,
+    pub name: String,
+    /// Finishing place, 1 is the winner. Players that died on the same turn share a place.
+    pub placement: usize,
+    pub death_turn: Option<usize>,
+    pub death_cause: Option<DeathCause>,
+    /// The player credited for the kill.
+    pub killed_by: Option<usize>,
+    /// Moves that went over the time budget.
+    #[serde(default)]
+    pub timeouts: usize,
+    /// Panic message when the bot panicked and was disqualified.
+    #[serde(default)]
+    pub panic: Option<String>,
+}
+
+/// A `PlayerResult` as found in saved game results, which used to store the death cause as text.
+#[derive(Deserialize)]
+struct StoredPlayerResult {
+    id: usize,
+    name: String,
+    placement: usize,
+    death_turn: Option<usize>,
+    death_cause: Option<StoredDeathCause>,
+    killed_by: Option<usize>,
+    #[serde(default)]
+    timeouts: usize,
+    #[serde(default)]
+    panic: Option<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredDeathCause {
+    Typed(DeathCause),
+    Legacy(String),
+}
+
+impl From<StoredPlayerResult> for PlayerResult {
+    fn from(stored: StoredPlayerResult) -> Self {
+        let death_cause = match stored.death_cause {
+            Some(StoredDeathCause::Typed(cause)) => Some(cause),
+            Some(StoredDeathCause::Legacy(name)) => DeathCause::from_legacy(&name, stored.killed_by),
+            None => None,
+        };
+        // Suicides used to be credited to the player itself
+        let killed_by = stored.killed_by.filter(|&killer| killer != stored.id);
+        PlayerResult {
+            id: stored.id,
+            name: stored.name,
+            placement: stored.placement,
+            death_turn: stored.death_turn,
+            death_cause,
+            killed_by,
+            timeouts: stored.timeouts,
+            panic: stored.panic,
+        }
+    }
+}
+
+impl PlayerResult {
+    pub fn from_players(players: &[Player]) -> Vec<Self> {
+        players
+            .iter()
+            .map(|player| PlayerResult {
+                id: player.id,
+                name: player.name.clone(),
+                placement: 1 + players.iter().filter(|other| Self::outlived(other, player)).count(),
+                death_turn: player.death_turn,
+                death_cause: player.death_cause,
+                killed_by: player.killed_by,
+                timeouts: 0,
+                panic: None,
+            })
+            .collect()
+    }
+
+    fn outlived(player: &Player, other: &Player) -> bool {
+        match (player.death_turn, other.death_turn) {
+            (None, Some(_)) => true,
+            (Some(turn), Some(other_turn)) => turn > other_turn,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+
+    fn dead_player(id: usize, death_turn: usize) -> Player {
+        let mut player = Player::new(format!("P{id}"), Coord::from(1, 1), id);
+        match id {
+            0 => player.kill(DeathCause::Suicide, None),
+            _ => player.kill(DeathCause::Bomb { owner: 0 }, Some(0)),
+        }
+        player.death_turn = Some(death_turn);
+        player
+    }
+
+    #[test]
+    fn test_from_players_places_same_turn_deaths_as_tie() {
+        //Arrange
+        let players = vec![
+            dead_player(0, 10),
+            Player::new("P1".to_string(), Coord::from(1, 1), 1),
+            dead_player(2, 20),
+            dead_player(3, 20),
+        ];
+
+        //Act
+        let results = PlayerResult::from_players(&players);
+
+        //Assert
+        let placements: Vec<usize> = results.iter().map(|result| result.placement).collect();
+        assert_eq!(placements, vec![4, 1, 2, 2]);
+        assert_eq!(results[0].death_cause, Some(DeathCause::Suicide));
+        assert_eq!(results[2].killed_by, Some(0));
+        assert_eq!(results[1].death_turn, None);
+    }
+
+    #[test]
+    fn test_reads_death_causes_stored_as_text() {
+        //Arrange
+        let json = r#"[
+            {"id": 0, "name": "P0", "placement": 3, "death_turn": 5, "death_cause": "suicide", "killed_by": 0},
+            {"id": 1, "name": "P1", "placement": 2, "death_turn": 8, "death_cause": "bomb", "killed_by": 2},
+            {"id": 2, "name": "P2", "placement": 1, "death_turn": null, "death_cause": null, "killed_by": null}
+        ]"#;
+
+        //Act
+        let results: Vec<PlayerResult> = serde_json::from_str(json).unwrap();
+
+        //Assert
+        assert_eq!(results[0].death_cause, Some(DeathCause::Suicide));
+        assert_eq!(results[0].killed_by, None);
+        assert_eq!(results[1].death_cause, Some(DeathCause::Bomb { owner: 2 }));
+        assert_eq!(results[1].killed_by, Some(2));
+        assert_eq!(results[2].death_cause, None);
+    }
+
+    #[test]
+    fn test_typed_death_cause_survives_a_round_trip() {
+        //Arrange
+        let players = vec![dead_player(1, 4), Player::new("P0".to_string(), Coord::from(1, 1), 0)];
+        let results = PlayerResult::from_players(&players);
+
+        //Act
+        let json = serde_json::to_string(&results).unwrap();
+        let read: Vec<PlayerResult> = serde_json::from_str(&json).unwrap();
+
+        //Assert
+        assert_eq!(read, results);
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+use crate::coord::Coord;
+
+/// A bomb a player tried to place while it already had its maximum number of bombs on the map.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RejectedBomb {
+    pub turn: usize,
+    pub player_id: usize,
+    pub position: Coord,
+}
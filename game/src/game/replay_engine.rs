@@ -1,5 +1,7 @@
 use crate::coord::Coord;
 use crate::game::game::{Game};
+use crate::game::game_error::GameError;
+use crate::game::replay_file::ReplayFile;
 use crate::map::bomb::Bomb;
 use crate::map::enums::command::Command;
 use crate::map::player::Player;
@@ -16,6 +18,9 @@ pub struct MapReplaySnapshot {
     pub turn: usize,
     pub players: Vec<Player>,
     pub bombs: Vec<Bomb>,
+    pub width: usize,
+    pub height: usize,
+    /// All cells row by row, so `row * width + col`.
     pub grid: Vec<char>,
     pub explosions: Vec<Coord>,
 }
@@ -29,18 +34,24 @@ impl<'a> ReplayEngine<'a> {
         Self { game }
     }
 
-    pub fn to_snapshot(&mut self, commands: &Vec<Vec<Command>>) -> GameReplaySnapshot {
+    /// Plays a saved replay from start to end.
+    pub fn snapshot_file(file: &ReplayFile) -> Result<GameReplaySnapshot, GameError> {
+        let mut game = file.game()?;
+        ReplayEngine::new(&mut game).to_snapshot(&file.commands)
+    }
+
+    pub fn to_snapshot(&mut self, commands: &Vec<Vec<Command>>) -> Result<GameReplaySnapshot, GameError> {
         let mut turn_snapshots = Vec::new();
         turn_snapshots.push(self.get_snapshot());
-        while !self.game.map.has_winner() {
-            self.game.run_round( Some(commands));
+        while !self.game.map.is_game_over() {
+            self.game.run_round( Some(commands))?;
             turn_snapshots.push(self.get_snapshot());
 
         }
-        GameReplaySnapshot {
+        Ok(GameReplaySnapshot {
             map_settings: self.game.map.map_settings.clone(),
             turns: turn_snapshots,
-        }
+        })
     }
 
     fn get_snapshot(&self) -> MapReplaySnapshot {
@@ -48,6 +59,8 @@ impl<'a> ReplayEngine<'a> {
                 turn: self.game.turn,
                 players: self.game.map.players.clone(),
                 bombs: self.game.map.bombs.clone(),
+                width: self.game.map.grid.width(),
+                height: self.game.map.grid.height(),
                 grid: self.game.map.grid.tiles.clone(),
                 explosions: self.game.map.explosions.clone(),
 
@@ -0,0 +1,204 @@
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::bot::bot_data::BotData;
+use crate::game::game::Game;
+use crate::game::game_error::GameError;
+use crate::game::game_result::GameResult;
+use crate::game::replay_file_error::ReplayFileError;
+use crate::game::replay_format::ReplayFormat;
+use crate::map::enums::command::Command;
+use crate::map::structs::map_config::MapConfig;
+
+/// Version of the replay file layout. Bump it when a change cannot be read by older engines.
+pub const REPLAY_FORMAT_VERSION: u32 = 2;
+/// Version of the engine that wrote a replay, for telling why an old replay plays out differently.
+pub const ENGINE_VERSION: &str = env!("CARGO_PKG_VERSION");
+/// Start of every binary replay, followed by the format version as little endian `u32`.
+const BINARY_MAGIC: &[u8; 4] = b"BMRP";
+
+/// Everything needed to play a game again without the bots.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReplayFile {
+    pub format_version: u32,
+    pub engine_version: String,
+    pub seed: u64,
+    pub map_config: MapConfig,
+    pub bots: Vec<BotData>,
+    /// Commands of every player, in player id order. Packed since version 2, see `packed_commands`.
+    #[serde(with = "crate::game::packed_commands")]
+    pub commands: Vec<Vec<Command>>,
+    #[serde(default)]
+    pub debug_data: Vec<Vec<String>>,
+}
+
+/// Read before the rest of a JSON replay, so a newer format is reported instead of failing to parse.
+#[derive(Deserialize)]
+struct ReplayHeader {
+    format_version: u32,
+}
+
+impl ReplayFile {
+    pub fn from_result(result: &GameResult) -> Self {
+        ReplayFile {
+            format_version: REPLAY_FORMAT_VERSION,
+            engine_version: ENGINE_VERSION.to_string(),
+            seed: result.seed,
+            map_config: result.game_settings.clone(),
+            bots: result.bots.clone(),
+            commands: result.replay_data.clone(),
+            debug_data: result.debug_data.clone(),
+        }
+    }
+
+    pub fn to_bytes(&self, format: ReplayFormat) -> Result<Vec<u8>, ReplayFileError> {
+        match format {
+            ReplayFormat::Json => Ok(serde_json::to_vec(self)?),
+            ReplayFormat::Binary => {
+                let mut bytes = BINARY_MAGIC.to_vec();
+                bytes.extend_from_slice(&self.format_version.to_le_bytes());
+                bytes.extend(rmp_serde::to_vec_named(self)?);
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Reads a replay in either format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReplayFileError> {
+        if let Some(rest) = bytes.strip_prefix(BINARY_MAGIC) {
+            let (version, payload) = rest.split_first_chunk::<4>().ok_or(ReplayFileError::UnknownFormat)?;
+            check_version(u32::from_le_bytes(*version))?;
+            return Ok(rmp_serde::from_slice(payload)?);
+        }
+        if bytes.trim_ascii_start().first() != Some(&b'{') {
+            return Err(ReplayFileError::UnknownFormat);
+        }
+        let header: ReplayHeader = serde_json::from_slice(bytes)?;
+        check_version(header.format_version)?;
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
+    pub fn save(&self, path: &Path, format: ReplayFormat) -> Result<(), ReplayFileError> {
+        std::fs::write(path, self.to_bytes(format)?)?;
+        Ok(())
+    }
+
+    pub fn load(path: &Path) -> Result<Self, ReplayFileError> {
+        Self::from_bytes(&std::fs::read(path)?)
+    }
+
+    /// The map settings of the game, with the seed it was played with.
+    pub fn map_settings(&self) -> MapConfig {
+        MapConfig { seed: self.seed, ..self.map_config.clone() }
+    }
+
+    /// A game without bots that plays this replay, for example with the `ReplayEngine`.
+    pub fn game(&self) -> Result<Game, GameError> {
+        Game::build(Vec::new(), self.map_settings(), Some(self.bots.clone()))
+    }
+
+    /// Plays the replay again and returns its result, with the recorded commands and debug output.
+    pub fn replay(&self) -> Result<GameResult, GameError> {
+        let mut result = self.game()?.run_game(Some(&self.commands))?;
+        result.replay_data = self.commands.clone();
+        result.debug_data = if self.debug_data.len() == self.commands.len() {
+            self.debug_data.clone()
+        } else {
+            self.commands.iter().map(|commands| vec![String::new(); commands.len()]).collect()
+        };
+        Ok(result)
+    }
+}
+
+fn check_version(version: u32) -> Result<(), ReplayFileError> {
+    if version > REPLAY_FORMAT_VERSION {
+        return Err(ReplayFileError::UnsupportedVersion { version, supported: REPLAY_FORMAT_VERSION });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bot::bot::{Bot, BotController};
+    use crate::bot::observation::Observation;
+    use rand::rngs::StdRng;
+
+    struct BomberBot;
+
+    impl Bot for BomberBot {
+        fn start_game(&mut self, _map_settings: &MapConfig, _bot_name: String, _bot_id: usize, _rng: StdRng) -> bool {
+            true
+        }
+
+        fn get_move(&mut self, observation: &Observation) -> Command {
+            match observation.turn % 4 {
+                0 => Command::PlaceBomb,
+                1 => Command::Down,
+                2 => Command::Right,
+                _ => Command::Wait,
+            }
+        }
+    }
+
+    fn played_game() -> GameResult {
+        let map_settings = MapConfig { size: 7, seed: 42, endgame: 20, ..Default::default() };
+        let bots = vec![
+            BotController::new(Box::new(BomberBot), "bomber1".to_string()),
+            BotController::new(Box::new(BomberBot), "bomber2".to_string()),
+        ];
+        Game::build(bots, map_settings, None).unwrap().run().unwrap()
+    }
+
+    #[test]
+    fn test_replay_file_round_trips_in_both_formats() {
+        //Arrange
+        let result = played_game();
+        let file = ReplayFile::from_result(&result);
+
+        for format in [ReplayFormat::Json, ReplayFormat::Binary] {
+            //Act
+            let read = ReplayFile::from_bytes(&file.to_bytes(format).unwrap()).unwrap();
+
+            //Assert
+            assert_eq!(read.format_version, REPLAY_FORMAT_VERSION);
+            assert_eq!(read.engine_version, ENGINE_VERSION);
+            assert_eq!(read.seed, 42);
+            assert_eq!(read.commands, result.replay_data);
+            assert_eq!(read.bots.iter().map(|bot| bot.name.clone()).collect::<Vec<_>>(), vec!["bomber1 (0)", "bomber2 (1)"]);
+        }
+    }
+
+    #[test]
+    fn test_replay_plays_out_like_the_original_game() {
+        //Arrange
+        let result = played_game();
+        let bytes = ReplayFile::from_result(&result).to_bytes(ReplayFormat::Binary).unwrap();
+
+        //Act
+        let replayed = ReplayFile::from_bytes(&bytes).unwrap().replay().unwrap();
+
+        //Assert
+        assert_eq!(replayed.winner, result.winner);
+        assert_eq!(replayed.rounds, result.rounds);
+        assert_eq!(replayed.replay_data, result.replay_data);
+        assert_eq!(replayed.debug_data, result.debug_data);
+    }
+
+    #[test]
+    fn test_newer_format_versions_are_refused() {
+        //Arrange
+        let mut file = ReplayFile::from_result(&played_game());
+        file.format_version = REPLAY_FORMAT_VERSION + 1;
+
+        //Act
+        let json = ReplayFile::from_bytes(&file.to_bytes(ReplayFormat::Json).unwrap());
+        let binary = ReplayFile::from_bytes(&file.to_bytes(ReplayFormat::Binary).unwrap());
+        let garbage = ReplayFile::from_bytes(b"not a replay");
+
+        //Assert
+        let newer = |result| matches!(result, Err(ReplayFileError::UnsupportedVersion { version, supported }) if version == supported + 1);
+        assert!(newer(json));
+        assert!(newer(binary));
+        assert!(matches!(garbage, Err(ReplayFileError::UnknownFormat)));
+    }
+}
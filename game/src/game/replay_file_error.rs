@@ -0,0 +1,65 @@
+use std::fmt;
+
+/// Why a replay file cannot be written or read.
+#[derive(Debug)]
+pub enum ReplayFileError {
+    Io(std::io::Error),
+    Json(serde_json::Error),
+    Encode(rmp_serde::encode::Error),
+    Decode(rmp_serde::decode::Error),
+    /// The file was written by a newer engine with a format this one does not know.
+    UnsupportedVersion { version: u32, supported: u32 },
+    /// Neither a JSON nor a binary replay.
+    UnknownFormat,
+}
+
+impl fmt::Display for ReplayFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayFileError::Io(err) => write!(f, "Cannot access replay file: {err}"),
+            ReplayFileError::Json(err) => write!(f, "Invalid JSON replay: {err}"),
+            ReplayFileError::Encode(err) => write!(f, "Cannot encode replay: {err}"),
+            ReplayFileError::Decode(err) => write!(f, "Invalid binary replay: {err}"),
+            ReplayFileError::UnsupportedVersion { version, supported } => {
+                write!(f, "Replay format version {version} is not supported, this engine reads up to version {supported}")
+            }
+            ReplayFileError::UnknownFormat => write!(f, "Not a replay file"),
+        }
+    }
+}
+
+impl std::error::Error for ReplayFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReplayFileError::Io(err) => Some(err),
+            ReplayFileError::Json(err) => Some(err),
+            ReplayFileError::Encode(err) => Some(err),
+            ReplayFileError::Decode(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for ReplayFileError {
+    fn from(err: std::io::Error) -> Self {
+        ReplayFileError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ReplayFileError {
+    fn from(err: serde_json::Error) -> Self {
+        ReplayFileError::Json(err)
+    }
+}
+
+impl From<rmp_serde::encode::Error> for ReplayFileError {
+    fn from(err: rmp_serde::encode::Error) -> Self {
+        ReplayFileError::Encode(err)
+    }
+}
+
+impl From<rmp_serde::decode::Error> for ReplayFileError {
+    fn from(err: rmp_serde::decode::Error) -> Self {
+        ReplayFileError::Decode(err)
+    }
+}
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// How a replay file is written. Reading detects the format by itself.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ReplayFormat {
+    /// Readable, and what the web viewer gets from the backend.
+    #[default]
+    Json,
+    /// MessagePack behind a small header, a fraction of the size for long games.
+    Binary,
+}
+
+impl ReplayFormat {
+    pub fn extension(&self) -> &'static str {
+        match self {
+            ReplayFormat::Json => "replay.json",
+            ReplayFormat::Binary => "replay.bin",
+        }
+    }
+}
@@ -4,15 +4,18 @@ use crate::{coord::Coord, map::{grid::cell::CellType, map::Map}};
 pub struct Bomb {
     pub position: Coord,
     pub timer: usize,
-    pub player_id: usize
+    pub player_id: usize,
+    /// Blast radius of the player that placed the bomb, including power-ups.
+    pub radius: usize,
 }
 
 impl Bomb {
-    pub fn new(position: Coord, timer: usize, player_id: usize) -> Bomb {
+    pub fn new(position: Coord, timer: usize, player_id: usize, radius: usize) -> Bomb {
         Bomb {
             position,
             timer,
-            player_id
+            player_id,
+            radius,
         }
     }
 
@@ -27,7 +30,7 @@ impl Bomb {
 
         for direction in directions.iter() {
             let mut current_loc = Some(self.position);
-            for _ in 1..= map.map_settings.bomb_radius {
+            for _ in 1..= self.radius {
                 current_loc = current_loc.and_then(direction);
 
                 if let Some(loc) = current_loc {
@@ -71,7 +74,7 @@ mod tests {
 
         let players = vec![Player::new("Player 1".to_string(), Coord::from(1, 1), 0), Player::new("Player 2".to_string(), Coord::from(1, 6), 1)];
 
-        let  map = &mut Map::new(map_settings, players);
+        let  map = &mut Map::new(map_settings, players).unwrap();
 
         map.grid.clear_destructable(Coord::from(3, 3));
         map.grid.clear_destructable(Coord::from(2, 3)); // up
@@ -79,7 +82,7 @@ mod tests {
         map.grid.clear_destructable(Coord::from(3, 2)); // left
         map.grid.clear_destructable(Coord::from(3, 4)); // right
 
-        let bomb = Bomb::new(Coord::from(3, 3), 0, 0);
+        let bomb = Bomb::new(Coord::from(3, 3), 0, 0, map.map_settings.bomb_radius);
 
         let result = bomb.explosion_locations(map);
 
@@ -98,7 +101,7 @@ mod tests {
         assert_eq!(result.len(), expected.len());
 
         for coord in expected {
-            assert_eq!(result.contains(&coord), true);
+            assert!(result.contains(&coord));
         }
     }
 
@@ -111,9 +114,9 @@ mod tests {
 
         let players = vec![Player::new("Player 1".to_string(), Coord::from(1, 1), 0), Player::new("Player 2".to_string(), Coord::from(1, 6), 1)];
 
-        let  map = &mut Map::new(map_settings, players);
+        let  map = &mut Map::new(map_settings, players).unwrap();
 
-        let bomb = Bomb::new(Coord::from(1, 1), 0, 0);
+        let bomb = Bomb::new(Coord::from(1, 1), 0, 0, map.map_settings.bomb_radius);
 
         let result = bomb.explosion_locations(map);
 
@@ -136,9 +139,9 @@ mod tests {
 
         let players = vec![Player::new("Player 1".to_string(), Coord::from(1, 1), 0), Player::new("Player 2".to_string(), Coord::from(1, 6), 1)];
 
-        let  map = &mut Map::new(map_settings, players);
+        let  map = &mut Map::new(map_settings, players).unwrap();
 
-        let bomb = Bomb::new(Coord::from(3, 3), 0, 0);
+        let bomb = Bomb::new(Coord::from(3, 3), 0, 0, map.map_settings.bomb_radius);
 
         let result = bomb.explosion_locations(map);
         
@@ -161,9 +164,9 @@ mod tests {
 
         let players = vec![Player::new("Player 1".to_string(), Coord::from(1, 1), 0), Player::new("Player 2".to_string(), Coord::from(1, 6), 1)];
 
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
 
-        let bomb = Bomb::new(Coord::from(2, 2), 0, 0);
+        let bomb = Bomb::new(Coord::from(2, 2), 0, 0, map.map_settings.bomb_radius);
 
         let result = bomb.explosion_locations(map);
 
@@ -0,0 +1,42 @@
+use crate::map::commands::traits::player_command::PlayerCommand;
+use crate::map::map::Map;
+
+pub struct Detonate;
+
+impl PlayerCommand for Detonate {
+    /// Bombs are stored in the order they were placed, so the first one of the player is the oldest.
+    /// It explodes when the bombs are processed at the end of the turn.
+    fn try_execute(&self, map: &mut Map, player_index: usize) {
+        if let Some(bomb) = map.bombs.iter_mut().find(|bomb| bomb.player_id == player_index) {
+            bomb.timer = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::map::commands::place_bomb::PlaceBomb;
+    use crate::map::player::Player;
+    use crate::map::structs::map_config::MapConfig;
+
+    #[test]
+    fn test_detonate_sets_off_oldest_bomb() {
+        //Arrange
+        let mut map = Map::new(MapConfig { size: 9, bomb_timer: 5, ..MapConfig::default() }, vec![
+            Player::new("player1".to_string(), Coord::from(1, 1), 0),
+        ]).unwrap();
+        PlaceBomb.try_execute(&mut map, 0);
+        map.players[0].position = Coord::from(5, 5);
+        PlaceBomb.try_execute(&mut map, 0);
+
+        //Act
+        Detonate.try_execute(&mut map, 0);
+        map.process_bombs();
+
+        //Assert
+        assert_eq!(map.bombs.len(), 1);
+        assert_eq!(map.bombs[0].position, Coord::from(5, 5));
+    }
+}
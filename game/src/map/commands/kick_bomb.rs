@@ -0,0 +1,86 @@
+use crate::map::commands::traits::player_command::PlayerCommand;
+use crate::map::enums::direction::Direction;
+use crate::map::enums::game_event::GameEvent;
+use crate::map::grid::cell::CellType;
+use crate::map::map::Map;
+
+pub struct KickBomb(pub Direction);
+
+impl PlayerCommand for KickBomb {
+    fn try_execute(&self, map: &mut Map, player_index: usize) {
+        let direction = self.0;
+        let Some(start) = direction.step(map.players[player_index].position) else {
+            return;
+        };
+        let Some(bomb_index) = map.bombs.iter().position(|bomb| bomb.position == start) else {
+            return;
+        };
+
+        let mut end = start;
+        while let Some(next) = direction.step(end).filter(|next| map.grid.cell_type(*next) == CellType::Empty) {
+            end = next;
+        }
+        if end == start {
+            return;
+        }
+
+        // The player that placed the bomb may still be standing on it
+        let vacated = if map.players.iter().any(|player| player.is_alive() && player.position == start) {
+            CellType::Player
+        } else {
+            CellType::Empty
+        };
+        map.bombs[bomb_index].position = end;
+        map.grid.set_cell(start, vacated);
+        map.grid.set_cell(end, CellType::Bomb);
+        map.record_event(GameEvent::BombKicked { player: player_index, from: start, to: end });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+    use crate::map::commands::place_bomb::PlaceBomb;
+    use crate::map::player::Player;
+    use crate::map::structs::map_config::MapConfig;
+
+    fn map_with_bomb_right_of_player() -> Map {
+        let mut map = Map::new(MapConfig { size: 9, ..MapConfig::default() }, vec![
+            Player::new("kicker".to_string(), Coord::from(1, 1), 0),
+            Player::new("bomber".to_string(), Coord::from(2, 1), 1),
+        ]).unwrap();
+        for col in 1..8 {
+            map.grid.set_cell(Coord::from(col, 1), CellType::Empty);
+        }
+        PlaceBomb.try_execute(&mut map, 1);
+        map
+    }
+
+    #[test]
+    fn test_kick_slides_bomb_until_it_hits_something() {
+        //Arrange
+        let mut map = map_with_bomb_right_of_player();
+        map.grid.set_cell(Coord::from(6, 1), CellType::Destroyable);
+
+        //Act
+        KickBomb(Direction::Right).try_execute(&mut map, 0);
+
+        //Assert
+        assert_eq!(map.bombs[0].position, Coord::from(5, 1));
+        assert_eq!(map.grid.cell_type(Coord::from(5, 1)), CellType::Bomb);
+        assert_eq!(map.grid.cell_type(Coord::from(2, 1)), CellType::Player);
+    }
+
+    #[test]
+    fn test_kick_without_adjacent_bomb_does_nothing() {
+        //Arrange
+        let mut map = map_with_bomb_right_of_player();
+
+        //Act
+        KickBomb(Direction::Down).try_execute(&mut map, 0);
+
+        //Assert
+        assert_eq!(map.bombs[0].position, Coord::from(2, 1));
+    }
+}
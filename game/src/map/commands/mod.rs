@@ -3,6 +3,8 @@ pub mod move_down;
 pub mod move_right;
 pub mod move_left;
 pub mod place_bomb;
+pub mod kick_bomb;
+pub mod detonate;
 pub mod wait;
 pub mod traits;
-mod move_action;
\ No newline at end of file
+pub(crate) mod move_action;
\ No newline at end of file
@@ -1,40 +1,140 @@
-use crate::{coord::{Coord, ValidCoord},map::{grid::cell::CellType, map::Map}};
-
-#[derive(Debug, Clone, Copy)]
-pub enum Direction {
-    Up,
-    Down,
-    Left,
-    Right,
-}
+use std::collections::BTreeMap;
+use rand::Rng;
+use crate::coord::Coord;
+use crate::map::enums::game_event::GameEvent;
+use crate::{coord::ValidCoord,map::{enums::direction::Direction, grid::cell::CellType, map::Map}};
 
+/// Moves one tile, or more with speed power-ups. The player stops at the first tile it cannot enter.
 pub fn try_move_player(map: &mut Map, player_index: usize, direction: Direction) {
+    let steps = 1 + map.players[player_index].stats.extra_speed;
+    for step in 0..steps {
+        if !try_step(map, player_index, direction) {
+            if step == 0 {
+                let player = &map.players[player_index];
+                map.record_event(GameEvent::MoveBlocked { player: player.id, position: player.position, direction });
+            }
+            break;
+        }
+    }
+}
+
+fn try_step(map: &mut Map, player_index: usize, direction: Direction) -> bool {
     let player = &map.players[player_index];
     let current = player.position;
-    if let Some(new_pos) = get_new_position(direction, current).valid(map.map_settings.size, map.map_settings.size) {
+    if let Some(new_pos) = direction.step(current).valid(map.map_settings.width(), map.map_settings.height()) {
         if map.grid.can_move_to(new_pos) {
+            let target = map.grid.cell_type(new_pos);
+            if target.is_power_up() {
+                map.players[player_index].stats.collect(target);
+            }
             map.players[player_index].move_position(new_pos);
             if map.grid.cell_type(current) != CellType::Bomb{
                 map.grid.set_cell(current, CellType::Empty);
             }
             map.grid.set_cell(new_pos, CellType::Player);
+            map.record_event(GameEvent::Moved { player: map.players[player_index].id, from: current, to: new_pos });
+            return true;
         }
     }
+    false
 }
 
-fn get_new_position(direction: Direction, coord: Coord) -> Option<Coord>{ 
-    match direction {
-        Direction::Up => coord.move_up(),
-        Direction::Down => coord.move_down(),
-        Direction::Left => coord.move_left(),
-        Direction::Right => coord.move_right()
+/// Moves all players at once, one tile per step, so moving does not depend on the player order.
+/// Players never swap places or enter a tile another player stays on. When several players step
+/// onto the same tile they all stay, unless `random_tie_break` is set, then one of them gets it.
+pub(crate) fn try_move_players_simultaneously(map: &mut Map, moves: &[(usize, Direction)], random_tie_break: bool) {
+    let mut moving: Vec<(usize, Direction, usize)> = moves
+        .iter()
+        .map(|&(player_index, direction)| (player_index, direction, 1 + map.players[player_index].stats.extra_speed))
+        .collect();
+
+    let mut first_step = true;
+    while !moving.is_empty() {
+        let mut targets: BTreeMap<Coord, Vec<usize>> = BTreeMap::new();
+        for &(player_index, direction, _) in &moving {
+            let Some(target) = direction.step(map.players[player_index].position).valid(map.map_settings.width(), map.map_settings.height()) else {
+                continue;
+            };
+            let cell = map.grid.cell_type(target);
+            if cell.is_walkable() || cell == CellType::Player {
+                targets.entry(target).or_default().push(player_index);
+            }
+        }
+
+        let mut steps: Vec<(usize, Coord)> = Vec::new();
+        for (target, contenders) in targets {
+            if contenders.len() == 1 {
+                steps.push((contenders[0], target));
+            } else if random_tie_break {
+                steps.push((contenders[map.rng.random_range(0..contenders.len())], target));
+            }
+        }
+        let position = |player_index: usize| map.players[player_index].position;
+        let swapping: Vec<usize> = steps
+            .iter()
+            .filter(|&&(player_index, target)| {
+                steps.iter().any(|&(other, other_target)| other_target == position(player_index) && position(other) == target)
+            })
+            .map(|&(player_index, _)| player_index)
+            .collect();
+        steps.retain(|(player_index, _)| !swapping.contains(player_index));
+        // Every cancelled step can block the player behind it, so repeat until nothing changes
+        loop {
+            let standing: Vec<Coord> = map
+                .players
+                .iter()
+                .filter(|player| player.is_alive() && !steps.iter().any(|&(player_index, _)| player_index == player.id))
+                .map(|player| player.position)
+                .collect();
+            let before = steps.len();
+            steps.retain(|(_, target)| !standing.contains(target));
+            if steps.len() == before {
+                break;
+            }
+        }
+
+        for &(player_index, _) in &steps {
+            let current = map.players[player_index].position;
+            if map.grid.cell_type(current) != CellType::Bomb {
+                map.grid.set_cell(current, CellType::Empty);
+            }
+        }
+        for &(player_index, target) in &steps {
+            let cell = map.grid.cell_type(target);
+            if cell.is_power_up() {
+                map.players[player_index].stats.collect(cell);
+            }
+            let from = map.players[player_index].position;
+            map.players[player_index].move_position(target);
+            map.grid.set_cell(target, CellType::Player);
+            map.record_event(GameEvent::Moved { player: map.players[player_index].id, from, to: target });
+        }
+        if first_step {
+            for &(player_index, direction, _) in &moving {
+                if !steps.iter().any(|&(moved, _)| moved == player_index) {
+                    let player = &map.players[player_index];
+                    map.record_event(GameEvent::MoveBlocked { player: player.id, position: player.position, direction });
+                }
+            }
+            first_step = false;
+        }
+
+        moving = moving
+            .into_iter()
+            .filter(|(player_index, _, _)| steps.iter().any(|(moved, _)| moved == player_index))
+            .map(|(player_index, direction, steps_left)| (player_index, direction, steps_left - 1))
+            .filter(|(_, _, steps_left)| *steps_left > 0)
+            .collect();
     }
 }
 
 #[cfg(test)]
 pub mod tests {
     use crate::coord::Coord;
-    use crate::map::commands::move_action::{try_move_player, Direction};
+    use crate::map::commands::move_action::{try_move_player, try_move_players_simultaneously};
+    use crate::map::enums::direction::Direction;
+    use crate::map::enums::game_event::GameEvent;
+    use crate::map::grid::cell::CellType;
     use crate::map::map::Map;
     use crate::map::player::Player;
     use crate::map::structs::map_config::MapConfig;
@@ -105,10 +205,116 @@ pub mod tests {
         test_move_command_cannot_move(Direction::Right, start)
     }
 
+    #[test]
+    fn test_speed_power_up_is_collected_and_moves_further() {
+        //Arrange
+        let start = Coord::from(5, 5);
+        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![Player::new("player1".to_string(), start, 0)]).unwrap();
+        map.grid.set_cell(Coord::from(5, 4), CellType::PowerUpSpeed);
+        map.grid.set_cell(Coord::from(5, 3), CellType::Empty);
+        map.grid.set_cell(Coord::from(5, 2), CellType::Empty);
+
+        // Act
+        try_move_player(&mut map, 0, Direction::Up);
+        try_move_player(&mut map, 0, Direction::Up);
+
+        // Assert
+        assert_eq!(map.players[0].stats.extra_speed, 1);
+        assert_eq!(map.players[0].position, Coord::from(5, 2));
+        assert_eq!(map.grid.cell_type(Coord::from(5, 4)), CellType::Empty);
+    }
+
+    /// Map with an open row 1 and the players on it.
+    fn open_row_map(columns: &[usize]) -> Map {
+        let players = columns.iter().enumerate().map(|(id, &col)| Player::new(format!("player{id}"), Coord::from(col, 1), id)).collect();
+        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() }, players).unwrap();
+        for col in 1..10 {
+            map.grid.set_cell(Coord::from(col, 1), CellType::Empty);
+        }
+        for &col in columns {
+            map.grid.set_cell(Coord::from(col, 1), CellType::Player);
+        }
+        map
+    }
+
+    #[test]
+    fn test_simultaneous_moves_onto_same_tile_bounce() {
+        //Arrange
+        let mut map = open_row_map(&[1, 3]);
+
+        //Act
+        try_move_players_simultaneously(&mut map, &[(0, Direction::Right), (1, Direction::Left)], false);
+
+        //Assert
+        assert_eq!(map.players[0].position, Coord::from(1, 1));
+        assert_eq!(map.players[1].position, Coord::from(3, 1));
+        assert_eq!(map.grid.cell_type(Coord::from(2, 1)), CellType::Empty);
+    }
+
+    #[test]
+    fn test_simultaneous_moves_record_steps_and_bounces() {
+        //Arrange
+        let mut map = open_row_map(&[1, 3, 6]);
+        map.map_settings.record_events = true;
+
+        //Act
+        try_move_players_simultaneously(&mut map, &[(0, Direction::Right), (1, Direction::Left), (2, Direction::Right)], false);
+
+        //Assert
+        assert_eq!(map.events, vec![
+            GameEvent::Moved { player: 2, from: Coord::from(6, 1), to: Coord::from(7, 1) },
+            GameEvent::MoveBlocked { player: 0, position: Coord::from(1, 1), direction: Direction::Right },
+            GameEvent::MoveBlocked { player: 1, position: Coord::from(3, 1), direction: Direction::Left },
+        ]);
+    }
+
+    #[test]
+    fn test_simultaneous_random_tie_break_lets_one_player_through() {
+        //Arrange
+        let mut map = open_row_map(&[1, 3]);
+
+        //Act
+        try_move_players_simultaneously(&mut map, &[(0, Direction::Right), (1, Direction::Left)], true);
+
+        //Assert
+        let on_tile: Vec<_> = map.players.iter().filter(|player| player.position == Coord::from(2, 1)).collect();
+        assert_eq!(on_tile.len(), 1);
+        assert_eq!(map.grid.cell_type(Coord::from(2, 1)), CellType::Player);
+    }
+
+    #[test]
+    fn test_simultaneous_players_cannot_swap() {
+        //Arrange
+        let mut map = open_row_map(&[2, 3]);
+
+        //Act
+        try_move_players_simultaneously(&mut map, &[(0, Direction::Right), (1, Direction::Left)], false);
+
+        //Assert
+        assert_eq!(map.players[0].position, Coord::from(2, 1));
+        assert_eq!(map.players[1].position, Coord::from(3, 1));
+    }
+
+    #[test]
+    fn test_simultaneous_player_follows_player_moving_away_but_not_one_standing_still() {
+        //Arrange
+        let mut map = open_row_map(&[2, 3, 6, 7]);
+
+        //Act
+        try_move_players_simultaneously(&mut map, &[(0, Direction::Right), (1, Direction::Right), (2, Direction::Right)], false);
+
+        //Assert
+        assert_eq!(map.players[0].position, Coord::from(3, 1));
+        assert_eq!(map.players[1].position, Coord::from(4, 1));
+        assert_eq!(map.players[2].position, Coord::from(6, 1));
+        assert_eq!(map.grid.cell_type(Coord::from(2, 1)), CellType::Empty);
+        assert_eq!(map.grid.cell_type(Coord::from(3, 1)), CellType::Player);
+    }
+
     pub fn test_move_command(direction: Direction, expected: Coord) {
         //Arrange
         let start = Coord::from(5, 5);
-        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![Player::new("player1".to_string(), start, 0)]);
+        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![Player::new("player1".to_string(), start, 0)]).unwrap();
 
         // Act
         try_move_player(&mut map, 0, direction);
@@ -122,7 +328,7 @@ pub mod tests {
 
     pub fn test_move_command_cannot_move(direction: Direction, start: Coord) {
         //Arrange
-        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![Player::new("player1".to_string(), start, 0)]);
+        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![Player::new("player1".to_string(), start, 0)]).unwrap();
         map.grid.set_wall(start.move_up().expect("Controlled test"));
         map.grid.set_wall(start.move_down().expect("Controlled test"));
         map.grid.set_wall(start.move_left().expect("Controlled test"));
@@ -1,4 +1,5 @@
-use crate::map::commands::move_action::{self, Direction};
+use crate::map::commands::move_action;
+use crate::map::enums::direction::Direction;
 use crate::map::commands::traits::player_command::PlayerCommand;
 use crate::map::map::Map;
 
@@ -1,3 +1,4 @@
+use crate::map::enums::game_event::GameEvent;
 use crate::map::grid::cell::CellType;
 use crate::map::commands::traits::player_command::PlayerCommand;
 use crate::map::map::Map;
@@ -7,8 +8,14 @@ pub struct PlaceBomb;
 impl PlayerCommand for PlaceBomb {
     fn try_execute(&self, map: &mut Map, player_index: usize) {
         let pos = map.players[player_index].position;
-        map.add_bomb(pos, player_index);
-        map.grid.set_cell(pos, CellType::Bomb);
+        if map.bomb_capacity(player_index).is_some_and(|capacity| map.active_bombs(player_index) >= capacity) {
+            map.rejected_bombs.push((player_index, pos));
+            return;
+        }
+        if map.add_bomb(pos, player_index) {
+            map.grid.set_cell(pos, CellType::Bomb);
+            map.record_event(GameEvent::BombPlaced { player: player_index, position: pos });
+        }
     }
 }
 
@@ -24,7 +31,7 @@ mod tests {
     #[test]
     fn test_place_bomb_basic() {
         //Arrange
-        let mut map = Map::new(MapConfig { size: 7, ..MapConfig::default() },  vec![]);
+        let mut map = Map::new(MapConfig { size: 7, ..MapConfig::default() },  vec![]).unwrap();
 
 
         let player_pos = Coord::from(3, 3);
@@ -38,10 +45,26 @@ mod tests {
         assert!(map.bombs[0].position == player_pos);
     }
 
+    #[test]
+    fn test_place_bomb_rejected_at_capacity() {
+        //Arrange
+        let mut map = Map::new(MapConfig { size: 7, max_bombs_per_player: Some(1), ..MapConfig::default() },  vec![]).unwrap();
+        map.players = vec![Player::new("player1".to_string(), Coord::from(3, 3), 0)];
+        PlaceBomb.try_execute(&mut map, 0);
+        map.players[0].position = Coord::from(3, 4);
+
+        // Act
+        PlaceBomb.try_execute(&mut map, 0);
+
+        // Assert
+        assert_eq!(map.bombs.len(), 1);
+        assert_eq!(map.rejected_bombs, vec![(0, Coord::from(3, 4))]);
+    }
+
     // #[test]
     // fn test_place_bomb_no_player() {
     //     // Arrange
-    //     let mut map = Map::new(MapConfig { size: 7, ..MapConfig::default() }, Arc::new(DefaultCommandFactory)).build();
+    //     let mut map = Map::new(MapConfig { size: 7, ..MapConfig::default() }, Arc::new(DefaultCommandFactory)).unwrap().build();
     //     // default has 2 players atm
     //     let player_index = 3;
 
@@ -22,7 +22,7 @@ mod tests {
     fn wait_should_not_move() {
         //Arrange
         let start = Coord::from(5, 5);
-        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![]);
+        let mut map = Map::new(MapConfig { size: 11, ..MapConfig::default() },  vec![]).unwrap();
         map.players = vec![Player::new("player1".to_string(), start, 0)];
 
         // Act
@@ -39,7 +39,7 @@ mod tests {
     fn wait_should_not_bomb() {
         // Arrange
         let config = MapConfig { size: 7, ..MapConfig::default() };
-        let mut map = Map::new(config,  vec![]);
+        let mut map = Map::new(config,  vec![]).unwrap();
 
         // Act
         let wait = Wait;
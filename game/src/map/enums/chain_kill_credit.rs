@@ -0,0 +1,14 @@
+use serde::{Deserialize, Serialize};
+
+/// Who gets the credit when a bomb that was set off by another bomb kills a player.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ChainKillCredit {
+    /// The owner of the bomb that started the chain reaction. This is how kills were credited
+    /// before chain reactions were tracked.
+    Trigger,
+    /// The owner of the bomb whose blast hit the player.
+    #[default]
+    Owner,
+    /// Both of them.
+    Both,
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
+use crate::map::enums::direction::Direction;
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Command {
     Up,
     Down,
@@ -8,6 +9,12 @@ pub enum Command {
     Right,
     Wait,
     PlaceBomb,
+    /// Pushes the bomb next to the player in this direction until it hits something.
+    /// Only allowed when `MapConfig::allow_kick` is set.
+    KickBomb(Direction),
+    /// Sets off the oldest bomb of the player at the end of this turn.
+    /// Only allowed when `MapConfig::allow_detonate` is set.
+    Detonate,
 }
 
 impl Command {
@@ -15,7 +22,36 @@ impl Command {
     pub fn is_move(&self) -> bool {
         match self {
             Command::Up | Command::Down | Command::Left | Command::Right => true,
-            Command::Wait | Command::PlaceBomb => false,
+            Command::Wait | Command::PlaceBomb | Command::KickBomb(_) | Command::Detonate => false,
         }
     }
-}
\ No newline at end of file
+
+    /// The direction of a move command, `None` for every other command.
+    pub fn direction(&self) -> Option<Direction> {
+        match self {
+            Command::Up => Some(Direction::Up),
+            Command::Down => Some(Direction::Down),
+            Command::Left => Some(Direction::Left),
+            Command::Right => Some(Direction::Right),
+            Command::Wait | Command::PlaceBomb | Command::KickBomb(_) | Command::Detonate => None,
+        }
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_commands_round_trip_through_json() {
+        //Arrange
+        let commands = vec![Command::PlaceBomb, Command::KickBomb(Direction::Left), Command::Detonate];
+
+        //Act
+        let json = serde_json::to_string(&commands).unwrap();
+        let parsed: Vec<Command> = serde_json::from_str(&json).unwrap();
+
+        //Assert
+        assert_eq!(json, r#"["PlaceBomb",{"KickBomb":"Left"},"Detonate"]"#);
+        assert_eq!(parsed, commands);
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+/// Why a player left the game.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeathCause {
+    /// Caught in the blast of a bomb placed by `owner`, with another player to credit for it.
+    Bomb { owner: usize },
+    /// Caught in an explosion without anyone else to credit, usually its own bomb.
+    Suicide,
+    /// Standing on a tile when the map shrank over it.
+    Shrink,
+    /// The bot went over its time budget while timeouts forfeit.
+    Timeout,
+    /// The bot panicked.
+    Disqualified,
+    /// The player ran out of commands without a known reason, as happens when replaying a game.
+    Forfeit,
+}
+
+impl DeathCause {
+    /// Reads the death causes that older game results stored as plain strings. Those did not
+    /// keep the bomb owner, so it is taken from the stored killer.
+    pub(crate) fn from_legacy(name: &str, killed_by: Option<usize>) -> Option<Self> {
+        match name {
+            "bomb" => killed_by.map(|owner| DeathCause::Bomb { owner }),
+            "suicide" => Some(DeathCause::Suicide),
+            "shrink" => Some(DeathCause::Shrink),
+            "forfeit" => Some(DeathCause::Forfeit),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DeathCause {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeathCause::Bomb { owner } => write!(f, "bomb of player {owner}"),
+            DeathCause::Suicide => f.write_str("suicide"),
+            DeathCause::Shrink => f.write_str("shrink"),
+            DeathCause::Timeout => f.write_str("timeout"),
+            DeathCause::Disqualified => f.write_str("disqualified"),
+            DeathCause::Forfeit => f.write_str("forfeit"),
+        }
+    }
+}
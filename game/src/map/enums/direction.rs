@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use crate::coord::Coord;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// The neighbouring coordinate in this direction, `None` when it falls off the top or left edge.
+    pub fn step(&self, coord: Coord) -> Option<Coord> {
+        match self {
+            Direction::Up => coord.move_up(),
+            Direction::Down => coord.move_down(),
+            Direction::Left => coord.move_left(),
+            Direction::Right => coord.move_right(),
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use crate::coord::Coord;
+use crate::map::enums::death_cause::DeathCause;
+use crate::map::enums::direction::Direction;
+
+/// Something that happened on the map during a turn, recorded when `MapConfig::record_events` is set.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    /// A player stepped one tile, a speed power-up gives several of these per turn.
+    Moved { player: usize, from: Coord, to: Coord },
+    /// A player wanted to move but could not leave its tile.
+    MoveBlocked { player: usize, position: Coord, direction: Direction },
+    BombPlaced { player: usize, position: Coord },
+    BombKicked { player: usize, from: Coord, to: Coord },
+    /// A bomb went off, `tiles` are all tiles its blast reached including its own.
+    BombExploded { owner: usize, position: Coord, tiles: Vec<Coord> },
+    /// A bomb was set off early by an explosion. `triggered_by` owns the bomb that started the chain.
+    ChainTriggered { owner: usize, position: Coord, triggered_by: usize },
+    BlockDestroyed { position: Coord },
+    PlayerKilled { player: usize, cause: DeathCause, killed_by: Option<usize> },
+    /// A tile turned into wall as the map shrank.
+    ShrinkTile { position: Coord },
+}
@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameOutcome {
+    /// The game is still running.
+    #[default]
+    InProgress,
+    /// Exactly one player survived, holds the id of that player.
+    Winner(usize),
+    /// All remaining players died on the same turn.
+    Draw,
+}
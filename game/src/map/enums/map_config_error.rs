@@ -0,0 +1,69 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Why a `MapConfig` cannot be played, as reported by the map validator chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapConfigError {
+    /// Width and height must be odd and within the allowed range.
+    InvalidSize { width: usize, height: usize },
+    BombTimerOutOfRange { timer: usize, allowed: RangeInclusive<usize> },
+    BombRadiusOutOfRange { radius: usize, allowed: RangeInclusive<usize> },
+    /// The map would only start shrinking after an unreasonably long game.
+    EndgameTooLate { endgame: usize, max: usize },
+    /// Quarter turns only fit square maps.
+    RotationalSymmetryNotSquare { width: usize, height: usize },
+    /// There are more players than places to start.
+    NotEnoughSpawns { spawns: usize, players: usize },
+    LayoutSizeMismatch { layout_width: usize, layout_height: usize, width: usize, height: usize },
+    /// The layout does not have exactly one cell for every position of the grid.
+    LayoutTileCount { tiles: usize, expected: usize },
+    /// Spawns are numbered from 1.
+    SpawnOutsideMap { spawn: usize },
+    SpawnNotEmpty { spawn: usize },
+    DuplicateSpawn { spawn: usize, other: usize },
+    SpawnBlocked { spawn: usize },
+    UnreachableSpawn { spawn: usize, from: usize },
+    UnfairSpawns { closest: usize, furthest: usize, max_difference: usize },
+}
+
+impl fmt::Display for MapConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MapConfigError::InvalidSize { width, height } => {
+                write!(f, "Invalid map size: {width}x{height}. Width and height must be odd and 7-20")
+            }
+            MapConfigError::BombTimerOutOfRange { timer, allowed } => {
+                write!(f, "Bomb timer {timer} must be between {} and {}", allowed.start(), allowed.end())
+            }
+            MapConfigError::BombRadiusOutOfRange { radius, allowed } => {
+                write!(f, "Bomb radius {radius} must be between {} and {}", allowed.start(), allowed.end())
+            }
+            MapConfigError::EndgameTooLate { endgame, max } => {
+                write!(f, "Endgame turn {endgame} is too late for this map, at most {max} is allowed")
+            }
+            MapConfigError::RotationalSymmetryNotSquare { width, height } => {
+                write!(f, "Rotational symmetry needs a square map, but the map is {width}x{height}")
+            }
+            MapConfigError::NotEnoughSpawns { spawns, players } => {
+                write!(f, "The map has {spawns} spawns, which is not enough for {players} players")
+            }
+            MapConfigError::LayoutSizeMismatch { layout_width, layout_height, width, height } => {
+                write!(f, "Map layout is {layout_width}x{layout_height}, but the map is {width}x{height}")
+            }
+            MapConfigError::LayoutTileCount { tiles, expected } => {
+                write!(f, "Map layout has {tiles} cells, expected {expected}")
+            }
+            MapConfigError::SpawnOutsideMap { spawn } => write!(f, "Spawn {spawn} is outside the map"),
+            MapConfigError::SpawnNotEmpty { spawn } => write!(f, "Spawn {spawn} is not on an empty cell"),
+            MapConfigError::DuplicateSpawn { spawn, other } => write!(f, "Spawn {spawn} is on the same cell as spawn {other}"),
+            MapConfigError::SpawnBlocked { spawn } => write!(f, "Spawn {spawn} has no free cell next to it"),
+            MapConfigError::UnreachableSpawn { spawn, from } => write!(f, "Spawn {spawn} cannot be reached from spawn {from}"),
+            MapConfigError::UnfairSpawns { closest, furthest, max_difference } => write!(
+                f,
+                "Spawns are unfair: the nearest opponent is between {closest} and {furthest} steps away, at most {max_difference} difference is allowed"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MapConfigError {}
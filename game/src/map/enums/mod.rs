@@ -1 +1,10 @@
-pub mod command;
\ No newline at end of file
+pub mod chain_kill_credit;
+pub mod command;
+pub mod death_cause;
+pub mod direction;
+pub mod game_event;
+pub mod game_outcome;
+pub mod map_config_error;
+pub mod move_resolution;
+pub mod shrink_pattern;
+pub mod symmetry;
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// In which order the commands of a turn are carried out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum MoveResolution {
+    /// One player after the other in id order, so later players see and react to the moves of
+    /// earlier ones.
+    #[default]
+    Sequential,
+    /// All players choose from the same state and move at once. Players stepping onto the same
+    /// tile all stay where they are.
+    SimultaneousBounce,
+    /// Like `SimultaneousBounce`, but one of the players stepping onto the same tile gets it,
+    /// drawn from the map seed.
+    SimultaneousRandom,
+}
+
+impl MoveResolution {
+    pub fn is_simultaneous(&self) -> bool {
+        *self != MoveResolution::Sequential
+    }
+}
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// How the map closes in once the endgame starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum ShrinkPattern {
+    /// One cell per turn, in a clockwise spiral from the top-left corner.
+    #[default]
+    Spiral,
+    /// The whole outer ring closes at once, every `interval` turns.
+    Rings { interval: usize },
+    /// One random cell per turn, derived from the map seed.
+    Random,
+    /// The map never shrinks. A game still running at the last turn is a draw.
+    None,
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// How a generated map repeats itself, so every corner spawn sees the same surroundings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Symmetry {
+    /// Mirrored over the horizontal and the vertical centre line.
+    Mirror,
+    /// The same after every quarter turn around the centre. Only fits square maps.
+    Rotational,
+}
+
+impl Symmetry {
+    /// The cells that must look the same as `(row, col)` on a map of `width` by `height`.
+    pub fn orbit(&self, row: usize, col: usize, width: usize, height: usize) -> [(usize, usize); 4] {
+        let (last_row, last_col) = (height - 1, width - 1);
+        match self {
+            Symmetry::Mirror => [(row, col), (row, last_col - col), (last_row - row, col), (last_row - row, last_col - col)],
+            Symmetry::Rotational => [(row, col), (col, last_row - row), (last_row - row, last_col - col), (last_col - col, row)],
+        }
+    }
+}
@@ -1,15 +1,17 @@
 use crate::map::commands::{
-    move_down::MoveDown, move_left::MoveLeft, move_right::MoveRight,
-    move_up::MoveUp, place_bomb::PlaceBomb, wait::Wait,
+    detonate::Detonate, kick_bomb::KickBomb, move_down::MoveDown, move_left::MoveLeft,
+    move_right::MoveRight, move_up::MoveUp, place_bomb::PlaceBomb, wait::Wait,
 };
 use crate::map::commands::traits::player_command::PlayerCommand;
 use crate::map::enums::command::Command;
+use crate::map::structs::map_config::MapConfig;
 
 
 pub struct CommandFactory;
 
 impl CommandFactory {
-    pub fn create(command: &Command) -> Option<Box<dyn PlayerCommand>> {
+    /// Returns `None` for commands the map settings do not allow, so they do nothing.
+    pub fn create(command: &Command, settings: &MapConfig) -> Option<Box<dyn PlayerCommand>> {
         match command {
             Command::Up => Some(Box::new(MoveUp)),
             Command::Down => Some(Box::new(MoveDown)),
@@ -17,6 +19,28 @@ impl CommandFactory {
             Command::Right => Some(Box::new(MoveRight)),
             Command::PlaceBomb => Some(Box::new(PlaceBomb)),
             Command::Wait => Some(Box::new(Wait)),
+            Command::KickBomb(direction) if settings.allow_kick => Some(Box::new(KickBomb(*direction))),
+            Command::Detonate if settings.allow_detonate => Some(Box::new(Detonate)),
+            Command::KickBomb(_) | Command::Detonate => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::enums::direction::Direction;
+
+    #[test]
+    fn test_optional_commands_need_their_flag() {
+        //Arrange
+        let classic = MapConfig::default();
+        let extended = MapConfig { allow_kick: true, allow_detonate: true, ..MapConfig::default() };
+
+        //Act & Assert
+        assert!(CommandFactory::create(&Command::KickBomb(Direction::Up), &classic).is_none());
+        assert!(CommandFactory::create(&Command::Detonate, &classic).is_none());
+        assert!(CommandFactory::create(&Command::KickBomb(Direction::Up), &extended).is_some());
+        assert!(CommandFactory::create(&Command::Detonate, &extended).is_some());
+    }
+}
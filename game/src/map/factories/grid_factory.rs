@@ -1,5 +1,15 @@
 
-use crate::{coord::Coord, map::grid::{cell::CellType, grid::Grid}};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use crate::{coord::Coord, map::grid::{cell::CellType, grid::Grid}, map::structs::map_layout::MapLayout};
+use crate::map::structs::map_config::MapConfig;
+use crate::map::enums::symmetry::Symmetry;
+use crate::map::structs::map_generation_config::MapGenerationConfig;
+use crate::map::validators::map_validator::validator::spawn_reachability_validator::SpawnReachabilityValidator;
+
+/// Random maps whose obstacles cut off a spawn are thrown away, after this many tries the
+/// obstacles are left out.
+const MAX_GENERATION_ATTEMPTS: usize = 20;
 
 pub struct GridFactory {
 }
@@ -21,22 +31,103 @@ WWWWWWW line 6
 
  */
 impl GridFactory {
-    pub fn new(size: usize, player_locations: Vec<Coord>) -> Grid {
-        let tiles = Self::generate_grid(size);
-        let mut grid = Grid::new(tiles, size);
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(width: usize, height: usize, player_locations: Vec<Coord>) -> Grid {
+        let tiles = Self::generate_grid(width, height);
+        let mut grid = Grid::new(tiles, width, height);
         Self::add_players(&mut grid, player_locations);
         grid
     }
 
-    fn generate_grid(size: usize) -> Vec<char> {
-        let mut grid = vec!['.'; size * size];
+    /// Builds the grid of a hand-designed map. Unlike the generated grid, nothing is cleared around
+    /// the players, the layout decides how much room they get.
+    pub fn from_layout(layout: &MapLayout, player_locations: Vec<Coord>) -> Grid {
+        let tiles = layout.tiles.iter().map(CellType::as_char).collect();
+        let mut grid = Grid::new(tiles, layout.width, layout.height);
+        for coord in player_locations {
+            grid.set_cell(coord, CellType::Player);
+        }
+        grid
+    }
+
+    /// Builds a random grid from the map seed. The pillars and outer wall stay, the other cells are
+    /// rolled once per symmetry orbit, so every corner spawn gets the same surroundings.
+    /// The validator chain makes sure a rectangular map is not asked for quarter turns.
+    pub fn generate(config: &MapConfig, generation: &MapGenerationConfig, player_locations: Vec<Coord>) -> Grid {
+        let (width, height) = (config.width(), config.height());
+        let symmetry = generation.symmetry;
+        let spawns = config.spawn_positions();
+        let mut rng = StdRng::seed_from_u64(config.seed);
+        let mut random_grid = |obstacle_density| Self::generate_random_grid(width, height, generation, symmetry, obstacle_density, &spawns, &mut rng);
+        let tiles = (0..MAX_GENERATION_ATTEMPTS)
+            .map(|_| random_grid(generation.obstacle_density))
+            .find(|tiles| Self::spawns_connected(tiles, width, height, &spawns))
+            .unwrap_or_else(|| random_grid(0.0));
+        let mut grid = Grid::new(tiles, width, height);
+        Self::add_players(&mut grid, player_locations);
+        grid
+    }
 
-        for row in 0..size {
-            for column in 0..size {
-                let walled = (row == 0 || row == size - 1 || column == 0 || column == size - 1) || (column.is_multiple_of(2) && row.is_multiple_of(2));
+    fn generate_random_grid(
+        width: usize,
+        height: usize,
+        generation: &MapGenerationConfig,
+        symmetry: Symmetry,
+        obstacle_density: f64,
+        spawns: &[Coord],
+        rng: &mut StdRng,
+    ) -> Vec<char> {
+        let mut grid = Self::generate_grid(width, height);
+
+        for row in 1..height - 1 {
+            for column in 1..width - 1 {
+                let index = row * width + column;
+                if grid[index] == 'W' {
+                    continue;
+                }
+                let (first_row, first_column) = symmetry.orbit(row, column, width, height).into_iter().min().unwrap();
+                if (first_row, first_column) != (row, column) {
+                    grid[index] = grid[first_row * width + first_column];
+                    continue;
+                }
+                let near_spawn = spawns.iter().any(|spawn| spawn.square_3x3().contains(&Coord::from(column, row)));
+                grid[index] = if !near_spawn && rng.random::<f64>() < obstacle_density {
+                    'W'
+                } else if rng.random::<f64>() < generation.destroyable_density {
+                    '.'
+                } else {
+                    ' '
+                };
+            }
+        }
+        grid
+    }
+
+    /// Whether all spawns can still reach each other once their surroundings are cleared.
+    fn spawns_connected(tiles: &[char], width: usize, height: usize, spawns: &[Coord]) -> bool {
+        let mut grid = Grid::new(tiles.to_vec(), width, height);
+        for spawn in spawns {
+            spawn.square_3x3().iter().for_each(|c| { grid.clear_destructable(*c); });
+        }
+        let layout = MapLayout {
+            metadata: Default::default(),
+            width,
+            height,
+            tiles: grid.tiles.iter().map(|tile| CellType::from_char(*tile)).collect(),
+            spawns: spawns.to_vec(),
+        };
+        SpawnReachabilityValidator::check_spawns(&layout).is_ok()
+    }
+
+    fn generate_grid(width: usize, height: usize) -> Vec<char> {
+        let mut grid = vec!['.'; width * height];
+
+        for row in 0..height {
+            for column in 0..width {
+                let walled = (row == 0 || row == height - 1 || column == 0 || column == width - 1) || (column.is_multiple_of(2) && row.is_multiple_of(2));
 
                 if walled {
-                    grid[row * size + column] = 'W';
+                    grid[row * width + column] = 'W';
                 }
             }
         }
@@ -48,7 +139,7 @@ impl GridFactory {
             coord
                 .square_3x3()
                 .iter()
-                .for_each(|c| grid.clear_destructable(*c));
+                .for_each(|c| { grid.clear_destructable(*c); });
             grid.set_cell(coord, CellType::Player);
         }
     }
@@ -57,6 +148,14 @@ impl GridFactory {
 #[cfg(test)]
 mod tests {
     use crate::{coord::Coord, map::factories::grid_factory::GridFactory};
+    use crate::map::enums::symmetry::Symmetry;
+    use crate::map::structs::map_config::MapConfig;
+    use crate::map::structs::map_generation_config::MapGenerationConfig;
+
+    fn generated(seed: u64, generation: &MapGenerationConfig) -> Vec<char> {
+        let config = MapConfig { size: 11, seed, ..MapConfig::default() };
+        GridFactory::generate(&config, generation, config.spawn_positions()).tiles
+    }
 
     #[test]
     fn test_prepare_grid_example() {
@@ -70,7 +169,7 @@ mod tests {
         // 6: WWWWWWW
         let size = 7;
 
-        let grid = GridFactory::new(size, [Coord::from(1, 1), Coord::from(5, 5)].to_vec());
+        let grid = GridFactory::new(size, size, [Coord::from(1, 1), Coord::from(5, 5)].to_vec());
 
         let expected_char_grid = vec![
             'W', 'W', 'W', 'W', 'W', 'W', 'W', 
@@ -84,4 +183,61 @@ mod tests {
 
         assert_eq!(grid.tiles, expected_char_grid);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_generate_is_symmetric_and_seeded() {
+        for symmetry in [Symmetry::Mirror, Symmetry::Rotational] {
+            //Arrange
+            let generation = MapGenerationConfig { destroyable_density: 0.5, obstacle_density: 0.2, symmetry };
+
+            //Act
+            let tiles = generated(7, &generation);
+
+            //Assert
+            for row in 0..11 {
+                for column in 0..11 {
+                    for (other_row, other_column) in symmetry.orbit(row, column, 11, 11) {
+                        assert_eq!(tiles[row * 11 + column], tiles[other_row * 11 + other_column], "{symmetry:?} at ({column}, {row})");
+                    }
+                }
+            }
+            assert_eq!(tiles, generated(7, &generation));
+            assert_ne!(tiles, generated(8, &generation));
+        }
+    }
+
+    #[test]
+    fn test_generate_keeps_spawns_reachable() {
+        //Arrange
+        let generation = MapGenerationConfig { destroyable_density: 0.0, obstacle_density: 1.0, symmetry: Symmetry::Mirror };
+        let spawns = MapConfig { size: 11, ..MapConfig::default() }.spawn_positions();
+
+        //Act
+        let tiles = generated(1, &generation);
+
+        //Assert
+        assert!(GridFactory::spawns_connected(&tiles, 11, 11, &spawns));
+        assert!(!tiles.contains(&'.'));
+    }
+
+    #[test]
+    fn test_generate_rectangular_map_is_mirrored() {
+        //Arrange
+        let config = MapConfig { width: Some(15), height: Some(9), seed: 3, ..MapConfig::default() };
+        let generation = MapGenerationConfig { symmetry: Symmetry::Mirror, ..MapGenerationConfig::default() };
+
+        //Act
+        let grid = GridFactory::generate(&config, &generation, config.spawn_positions());
+
+        //Assert
+        assert_eq!((grid.width(), grid.height()), (15, 9));
+        for row in 0..9 {
+            for column in 0..15 {
+                for (other_row, other_column) in Symmetry::Mirror.orbit(row, column, 15, 9) {
+                    assert_eq!(grid.tiles[row * 15 + column], grid.tiles[other_row * 15 + other_column]);
+                }
+            }
+        }
+        assert_eq!(grid.cell_type(Coord::from(13, 7)), crate::map::grid::cell::CellType::Player);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod grid_factory;
-pub mod command_factory;
\ No newline at end of file
+pub mod command_factory;
+pub mod shrink_factory;
\ No newline at end of file
@@ -0,0 +1,22 @@
+use crate::map::enums::shrink_pattern::ShrinkPattern;
+use crate::map::shrink::no_shrink::NoShrink;
+use crate::map::shrink::random_shrink::RandomShrink;
+use crate::map::shrink::ring_shrink::RingShrink;
+use crate::map::shrink::spiral_shrink::SpiralShrink;
+use crate::map::shrink::traits::shrink_strategy::ShrinkStrategy;
+use crate::map::structs::map_config::MapConfig;
+
+pub struct ShrinkFactory;
+
+impl ShrinkFactory {
+    pub fn create(settings: &MapConfig) -> Box<dyn ShrinkStrategy> {
+        let (width, height) = (settings.width(), settings.height());
+        match settings.shrink {
+            ShrinkPattern::Spiral => Box::new(SpiralShrink { width, height }),
+            ShrinkPattern::Rings { interval } => Box::new(RingShrink::new(width, height, interval)),
+            ShrinkPattern::Random => Box::new(RandomShrink::new(width, height, settings.seed)),
+            // Lasts as long as the spiral would, so the game length does not change.
+            ShrinkPattern::None => Box::new(NoShrink { duration: (width - 2) * (height - 2) }),
+        }
+    }
+}
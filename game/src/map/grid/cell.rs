@@ -1,10 +1,24 @@
-#[derive(Clone, PartialEq, Debug)]
+use serde::{Deserialize, Serialize};
+
+/// Serialized as the same single character as `as_char`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum CellType {
+    #[serde(rename = " ")]
     Empty,       // ' '
+    #[serde(rename = "B")]
     Bomb,        // 'B'
+    #[serde(rename = "W")]
     Wall,        // 'W'
+    #[serde(rename = "P")]
     Player,       // 'P'
+    #[serde(rename = ".")]
     Destroyable, // '.'
+    #[serde(rename = "b")]
+    PowerUpBomb,   // 'b'
+    #[serde(rename = "r")]
+    PowerUpRadius, // 'r'
+    #[serde(rename = "s")]
+    PowerUpSpeed,  // 's'
 }
 
 impl CellType {
@@ -15,6 +29,9 @@ impl CellType {
             CellType::Wall => 'W',
             CellType::Player => 'P',
             CellType::Destroyable => '.',
+            CellType::PowerUpBomb => 'b',
+            CellType::PowerUpRadius => 'r',
+            CellType::PowerUpSpeed => 's',
         }
     }
 
@@ -25,7 +42,19 @@ impl CellType {
             'W' => CellType::Wall,
             'P' => CellType::Player,
             '.' => CellType::Destroyable,
+            'b' => CellType::PowerUpBomb,
+            'r' => CellType::PowerUpRadius,
+            's' => CellType::PowerUpSpeed,
             _ => CellType::Empty, // fallback
         }
     }
+
+    pub fn is_power_up(&self) -> bool {
+        matches!(self, CellType::PowerUpBomb | CellType::PowerUpRadius | CellType::PowerUpSpeed)
+    }
+
+    /// Players can walk onto empty cells and power-ups.
+    pub fn is_walkable(&self) -> bool {
+        *self == CellType::Empty || self.is_power_up()
+    }
 }
@@ -2,17 +2,27 @@ use crate::{coord::Coord, map::grid::cell::CellType};
 
 pub struct Grid {
     pub tiles: Vec<char>,
-    size: usize,
+    width: usize,
+    height: usize,
 }
 
 impl Grid {
-    pub fn new(tiles: Vec<char>, size: usize) -> Self {
+    pub fn new(tiles: Vec<char>, width: usize, height: usize) -> Self {
         Self {
-            tiles: tiles,
-            size: size
+            tiles,
+            width,
+            height,
         }
     }
 
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
     pub fn cell_type(&self, position: Coord) -> CellType {
         if self.out_of_bounds(&position){
             return CellType::Wall;
@@ -22,27 +32,30 @@ impl Grid {
     }
 
     pub fn cell_index(&self, position: &Coord) -> usize {
-        position.row.get() * self.size + position.col.get()
+        position.row.get() * self.width + position.col.get()
     }
 
     pub fn out_of_bounds(&self, position: &Coord) -> bool {
-        !position.is_valid(self.size, self.size)
+        !position.is_valid(self.width, self.height)
     }
 
-    pub(crate) fn clear_destructable(&mut self, location: Coord) {
+    /// Returns whether a destroyable tile was cleared, so a power-up can drop in its place.
+    pub(crate) fn clear_destructable(&mut self, location: Coord) -> bool {
         if self.cell_type(location) == CellType::Destroyable {
             self.set_cell(location, CellType::Empty);
+            return true;
         }
+        false
     }
 
     pub(crate) fn set_wall(&mut self, position: Coord) {
-        if position.is_valid(self.size, self.size) {
+        if position.is_valid(self.width, self.height) {
             self.set_cell(position, CellType::Wall);
         }
     }
 
     pub(crate) fn set_cell(&mut self, position: Coord, cell_type: CellType) {
-        if position.is_valid(self.size, self.size) {
+        if position.is_valid(self.width, self.height) {
             let idx = self.cell_index(&position);
             self.tiles[idx] = cell_type.as_char();
         }
@@ -50,7 +63,7 @@ impl Grid {
 
 
     pub fn can_move_to(&self, coord: Coord) -> bool {
-        self.cell_type(coord) == CellType::Empty
+        self.cell_type(coord).is_walkable()
     }
 
 
@@ -64,7 +77,7 @@ mod tests {
     fn test_cell_type_returns_wall_out_of_bounds() {
         //Arrange
         let tiles = vec![' '; 5 * 5];
-        let grid = Grid::new(tiles, 5);
+        let grid = Grid::new(tiles, 5, 5);
 
         // Act & Assert
         assert_eq!(grid.cell_type(Coord::from(5, 5)), CellType::Wall);
@@ -80,7 +93,7 @@ mod tests {
         tiles[1] = '.'; // (1, 0)
         tiles[3] = 'P'; // (0, 1)
         tiles[4] = 'B'; // (1, 1)
-        let grid = Grid::new(tiles, 3);
+        let grid = Grid::new(tiles, 3, 3);
 
         // Act & Assert
         assert_eq!(grid.cell_type(Coord::from(0, 0)), CellType::Wall);
@@ -93,7 +106,7 @@ mod tests {
     #[test]
     fn test_cell_index_calculation() {
         //Arrange
-        let grid = Grid::new(vec![' '; 7 * 7], 7);
+        let grid = Grid::new(vec![' '; 7 * 7], 7, 7);
 
         // Act & Assert
         assert_eq!(grid.cell_index(&Coord::from(0, 0)), 0);
@@ -102,28 +115,43 @@ mod tests {
         assert_eq!(grid.cell_index(&Coord::from(3, 2)), 2 * 7 + 3);
     }
 
+    #[test]
+    fn test_rectangular_grid_uses_width_for_rows() {
+        //Arrange
+        let mut grid = Grid::new(vec![' '; 9 * 5], 9, 5);
+
+        //Act
+        grid.set_cell(Coord::from(8, 4), CellType::Wall);
+
+        //Assert
+        assert_eq!(grid.cell_index(&Coord::from(8, 4)), 4 * 9 + 8);
+        assert_eq!(grid.tiles[44], 'W');
+        assert!(grid.out_of_bounds(&Coord::from(4, 5)));
+        assert!(!grid.out_of_bounds(&Coord::from(8, 0)));
+    }
+
     #[test]
     fn test_out_of_bounds_detection() {
         //Arrange
-        let grid = Grid::new(vec![' '; 5 * 5], 5);
+        let grid = Grid::new(vec![' '; 5 * 5], 5, 5);
 
         // Act & Assert
         // Valid bounds
-        assert_eq!(grid.out_of_bounds(&Coord::from(0, 0)), false);
-        assert_eq!(grid.out_of_bounds(&Coord::from(4, 4)), false);
-        assert_eq!(grid.out_of_bounds(&Coord::from(2, 2)), false);
+        assert!(!grid.out_of_bounds(&Coord::from(0, 0)));
+        assert!(!grid.out_of_bounds(&Coord::from(4, 4)));
+        assert!(!grid.out_of_bounds(&Coord::from(2, 2)));
 
         // Out of bounds
-        assert_eq!(grid.out_of_bounds(&Coord::from(5, 0)), true);
-        assert_eq!(grid.out_of_bounds(&Coord::from(0, 5)), true);
-        assert_eq!(grid.out_of_bounds(&Coord::from(5, 5)), true);
+        assert!(grid.out_of_bounds(&Coord::from(5, 0)));
+        assert!(grid.out_of_bounds(&Coord::from(0, 5)));
+        assert!(grid.out_of_bounds(&Coord::from(5, 5)));
     }
 
     #[test]
     fn test_set_cell_changes_cell_type() {
         //Arrange
         let tiles = vec![' '; 5 * 5];
-        let mut grid = Grid::new(tiles, 5);
+        let mut grid = Grid::new(tiles, 5, 5);
 
         //Act
         grid.set_cell(Coord::from(1, 1), CellType::Wall);
@@ -143,7 +171,7 @@ mod tests {
         tiles[0] = '.'; // (0, 0) is destroyable
         tiles[1] = 'W'; // (1, 0) is wall
         tiles[2] = ' '; // (2, 0) is empty
-        let mut grid = Grid::new(tiles, 5);
+        let mut grid = Grid::new(tiles, 5, 5);
 
         //Act
         grid.clear_destructable(Coord::from(0, 0));
@@ -163,22 +191,22 @@ mod tests {
         tiles[0] = ' '; // (0, 0) is empty
         tiles[1] = 'W'; // (1, 0) is wall
         tiles[2] = '.'; // (2, 0) is destroyable
-        let grid = Grid::new(tiles, 5);
+        let grid = Grid::new(tiles, 5, 5);
 
         //Act & Assert
-        assert_eq!(grid.can_move_to(Coord::from(0, 0)), true);
-        assert_eq!(grid.can_move_to(Coord::from(1, 0)), false);
-        assert_eq!(grid.can_move_to(Coord::from(2, 0)), false);
+        assert!(grid.can_move_to(Coord::from(0, 0)));
+        assert!(!grid.can_move_to(Coord::from(1, 0)));
+        assert!(!grid.can_move_to(Coord::from(2, 0)));
     }
 
     #[test]
     fn test_can_move_to_out_of_bounds_is_false() {
         //Arrange
-        let grid = Grid::new(vec![' '; 5 * 5], 5);
+        let grid = Grid::new(vec![' '; 5 * 5], 5, 5);
 
         //Act & Assert
-        assert_eq!(grid.can_move_to(Coord::from(5, 5)), false);
-        assert_eq!(grid.can_move_to(Coord::from(10, 0)), false);
+        assert!(!grid.can_move_to(Coord::from(5, 5)));
+        assert!(!grid.can_move_to(Coord::from(10, 0)));
     }
 }
 
@@ -1,13 +1,22 @@
-use std::usize;
-
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use crate::coord::Coord;
+use crate::game::game_error::GameError;
 use crate::map::bomb::{Bomb};
 use crate::map::grid::cell::CellType;
+use crate::map::enums::chain_kill_credit::ChainKillCredit;
 use crate::map::enums::command::Command;
+use crate::map::enums::death_cause::DeathCause;
+use crate::map::commands::move_action::try_move_players_simultaneously;
+use crate::map::enums::game_event::GameEvent;
+use crate::map::enums::game_outcome::GameOutcome;
+use crate::map::enums::move_resolution::MoveResolution;
 use crate::map::factories::command_factory::CommandFactory;
 use crate::map::factories::grid_factory::GridFactory;
 use crate::map::player::Player;
-use crate::map::shrink::calculate_shrink_location;
+use crate::map::factories::shrink_factory::ShrinkFactory;
+use crate::map::shrink::traits::shrink_strategy::ShrinkStrategy;
+use crate::map::structs::kill_record::KillRecord;
 use crate::map::structs::map_config::MapConfig;
 use crate::map::validators::map_validator::map_validator_chain_factory::MapValidatorChainFactory;
 use super::grid::grid::Grid;
@@ -18,52 +27,135 @@ pub struct Map {
     pub players: Vec<Player>,
     pub bombs: Vec<Bomb>,
     pub(crate) explosions: Vec<Coord>,
-    pub(crate) winner: Option<Player>,
+    pub(crate) outcome: GameOutcome,
+    /// Bombs refused this round because the player was at its limit, as player id and position.
+    pub(crate) rejected_bombs: Vec<(usize, Coord)>,
+    /// Decides power-up drops and move tie-breaks. Seeded from the map seed, so replays play out the same.
+    pub(crate) rng: StdRng,
+    shrink: Box<dyn ShrinkStrategy>,
+    /// Every player that died so far, in order of death.
+    pub kills: Vec<KillRecord>,
+    /// Kills of the turn that is being processed, they get their turn in `record_deaths`.
+    pending_kills: Vec<KillRecord>,
+    /// Events since the game last collected them, only kept when `MapConfig::record_events` is set.
+    pub(crate) events: Vec<GameEvent>,
 }
 
 
 impl Map {
-    pub fn new(config: MapConfig, players: Vec<Player>) -> Self {
-        MapValidatorChainFactory::validate(&config).expect("Map validation failed");
-        let size = config.size.clone();
-        Self {
+    /// Builds the map for `players`, or tells why `config` cannot be played by them.
+    pub fn new(config: MapConfig, players: Vec<Player>) -> Result<Self, GameError> {
+        MapValidatorChainFactory::validate(&config, players.len())?;
+        let seed = config.seed;
+        let shrink = ShrinkFactory::create(&config);
+        let player_locations = players.iter().map(|p| p.position).collect();
+        let grid = match (&config.layout, &config.generation) {
+            (Some(layout), _) => GridFactory::from_layout(layout, player_locations),
+            (None, Some(generation)) => GridFactory::generate(&config, generation, player_locations),
+            (None, None) => GridFactory::new(config.width(), config.height(), player_locations),
+        };
+        Ok(Self {
             map_settings: config,
-            grid: GridFactory::new(size, players.iter().map(|p| p.position).collect()),
-            players: players,
+            grid,
+            players,
             bombs: Vec::new(),
             explosions: Vec::new(),
-            winner: None
-        }
+            outcome: GameOutcome::InProgress,
+            rejected_bombs: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+            shrink,
+            kills: Vec::new(),
+            pending_kills: Vec::new(),
+            events: Vec::new(),
+        })
     }
 
 
 ///////////////////////////////////////////////////////////////////////////
-/// Handle players
+// Handle players
 ///////////////////////////////////////////////////////////////////////////
 
-    fn check_winner(&mut self) {
-        let alive_players = self.get_alive_players();
-        let alive_count = alive_players.len();
-        if alive_count == 1 {
-            self.winner = alive_players.first().cloned().cloned();
+    /// Decides the outcome once the turn is fully processed, so players that die on the same turn
+    /// end in a draw instead of the last one processed being the winner.
+    pub(crate) fn update_outcome(&mut self) {
+        if self.outcome != GameOutcome::InProgress {
+            return;
         }
+        let alive_players = self.get_alive_players();
+        self.outcome = match alive_players.as_slice() {
+            [] => GameOutcome::Draw,
+            [winner] => GameOutcome::Winner(winner.id),
+            _ => GameOutcome::InProgress,
+        };
+    }
+
+    pub fn outcome(&self) -> GameOutcome {
+        self.outcome
     }
 
     pub fn has_winner(&self) -> bool{
-        self.winner.is_some()
+        matches!(self.outcome, GameOutcome::Winner(_))
+    }
+
+    pub fn is_game_over(&self) -> bool {
+        self.outcome != GameOutcome::InProgress
     }
 
     pub(crate) fn get_player(&self, id: usize) -> Option<&Player> {
         self.players.iter().find(|player| player.id == id)
     }
 
-    pub(crate) fn kill_at_location(&mut self, location: Coord, reason_killed: String, killed_by: usize) {
-        if let Some(player) = self.players.iter_mut().find(|player| player.position.col.get() == location.col.get() && player.position.row.get() == location.row.get() && player.is_alive()) {
-            player.kill(&reason_killed, killed_by);
-            if reason_killed == "bomb" {
-                self.grid.set_cell(player.position, CellType::Empty);
-            }
-            self.check_winner();
+    pub(crate) fn record_event(&mut self, event: GameEvent) {
+        if self.map_settings.record_events {
+            self.events.push(event);
+        }
+    }
+
+    /// Kills the player on `location`. For bomb kills `triggered_by` owns the bomb that started the
+    /// chain reaction, who gets the credit follows `MapConfig::chain_kill_credit`.
+    pub(crate) fn kill_at_location(&mut self, location: Coord, cause: DeathCause, triggered_by: Option<usize>) {
+        let Some(index) = self.players.iter().position(|player| player.position == location && player.is_alive()) else {
+            return;
+        };
+        let victim = self.players[index].id;
+        let bomb_owner = match cause {
+            DeathCause::Bomb { owner } => Some(owner),
+            _ => None,
+        };
+        let mut credited = match (bomb_owner, triggered_by) {
+            (Some(owner), Some(trigger)) => match self.map_settings.chain_kill_credit {
+                ChainKillCredit::Trigger => vec![trigger],
+                ChainKillCredit::Owner => vec![owner],
+                ChainKillCredit::Both if owner == trigger => vec![owner],
+                ChainKillCredit::Both => vec![owner, trigger],
+            },
+            _ => Vec::new(),
+        };
+        credited.retain(|&killer| killer != victim);
+        // A blast without anyone else to credit is a suicide
+        let cause = if bomb_owner.is_some() && credited.is_empty() { DeathCause::Suicide } else { cause };
+
+        let killed_by = credited.first().copied();
+        self.players[index].kill(cause, killed_by);
+        if self.grid.cell_type(location) == CellType::Player {
+            self.grid.set_cell(location, CellType::Empty);
+        }
+        self.record_event(GameEvent::PlayerKilled { player: victim, cause, killed_by });
+        self.pending_kills.push(KillRecord { turn: 0, victim, cause, bomb_owner, triggered_by, credited });
+    }
+
+    /// Stores the turn of death for every player that died since the last call.
+    pub(crate) fn record_deaths(&mut self, turn: usize) {
+        for player in self.players.iter_mut().filter(|player| !player.is_alive() && player.death_turn.is_none()) {
+            player.death_turn = Some(turn);
+        }
+        self.kills.extend(self.pending_kills.drain(..).map(|kill| KillRecord { turn, ..kill }));
+    }
+
+    /// Removes a player that gave up, for example because its bot ran out of time.
+    pub(crate) fn forfeit_player(&mut self, player_id: usize, cause: DeathCause) {
+        if let Some(position) = self.get_player(player_id).filter(|player| player.is_alive()).map(|player| player.position) {
+            self.kill_at_location(position, cause, None);
         }
     }
 
@@ -80,39 +172,80 @@ impl Map {
     }
 
 ///////////////////////////////////////////////////////////////////////////
-/// Handle player input
+// Handle player input
 ///////////////////////////////////////////////////////////////////////////
     pub(crate) fn try_execute_command(&mut self, player: usize, command: Command) {
-        if let Some(cmd) = CommandFactory::create(&command) {
+        if let Some(cmd) = CommandFactory::create(&command, &self.map_settings) {
             cmd.try_execute(self, player);
         }
     }
 
+    /// Carries out the commands of all players as if they happen at the same time. Bombs are placed, kicked and detonated before anyone moves, so a player cannot
+    /// dodge a tile that is taken in the same turn.
+    pub(crate) fn execute_simultaneously(&mut self, commands: Vec<(usize, Command)>) {
+        let mut moves = Vec::new();
+        for (player, command) in commands {
+            match command.direction() {
+                Some(direction) => moves.push((player, direction)),
+                None => self.try_execute_command(player, command),
+            }
+        }
+        let random_tie_break = self.map_settings.move_resolution == MoveResolution::SimultaneousRandom;
+        try_move_players_simultaneously(self, &moves, random_tie_break);
+    }
+
 ///////////////////////////////////////////////////////////////////////////
-/// Handle shrink
+// Handle shrink
 ///////////////////////////////////////////////////////////////////////////
 
+    /// The turn by which the map has shrunk completely, so the game cannot last longer.
+    pub fn max_turn(&self) -> usize {
+        self.map_settings.endgame + self.shrink.duration()
+    }
+
+    /// The cells that become wall at the end of `turn`, none before the endgame.
+    pub fn shrink_cells(&self, turn: usize) -> Vec<Coord> {
+        match turn.checked_sub(self.map_settings.endgame) {
+            Some(step) => self.shrink.cells(step),
+            None => Vec::new(),
+        }
+    }
+
     pub(crate) fn handle_shrink(&mut self, turn: usize){
-        let shrink_turn = turn - self.map_settings.endgame;
-        if let Some(shrink_location) = calculate_shrink_location(shrink_turn, self.map_settings.size) {
+        for shrink_location in self.shrink_cells(turn) {
             self.grid.set_wall(shrink_location);
+            self.record_event(GameEvent::ShrinkTile { position: shrink_location });
             self.remove_bombs_at_location(shrink_location);
-            self.kill_at_location(shrink_location, "shrink".to_string(), usize::MAX);
-        } else {
-            panic!("No valid shrink location found for shrink {}", shrink_turn);
-        };
+            self.kill_at_location(shrink_location, DeathCause::Shrink, None);
+        }
     }
 
 ///////////////////////////////////////////////////////////////////////////
-/// Handle bombs
+// Handle bombs
 ///////////////////////////////////////////////////////////////////////////
 
-    pub(super) fn add_bomb(&mut self, position: Coord, player: usize) {
+    /// Returns whether the bomb was placed, which fails when there already is a bomb on the tile.
+    pub(super) fn add_bomb(&mut self, position: Coord, player: usize) -> bool {
         if self.bombs.iter().any(|bomb| bomb.position == position) {
-            return;
+            return false;
         }
+        let extra_radius = self.get_player(player).map_or(0, |player| player.stats.extra_radius);
         let timer = self.map_settings.bomb_timer;
-        self.bombs.push(Bomb::new(position, timer, player));
+        let radius = self.map_settings.bomb_radius + extra_radius;
+        self.bombs.push(Bomb::new(position, timer, player, radius));
+        true
+    }
+
+    pub fn active_bombs(&self, player: usize) -> usize {
+        self.bombs.iter().filter(|bomb| bomb.player_id == player).count()
+    }
+
+    /// Bombs a player can have on the map at once, `None` when there is no limit.
+    pub fn bomb_capacity(&self, player: usize) -> Option<usize> {
+        let settings = &self.map_settings;
+        let base = settings.max_bombs_per_player.or(settings.power_ups.enabled.then_some(1))?;
+        let extra_bombs = self.get_player(player).map_or(0, |player| player.stats.extra_bombs);
+        Some(base + extra_bombs)
     }
     
     pub(crate) fn bomb_timer_decrease(&mut self) {
@@ -134,7 +267,7 @@ impl Map {
     }
 
 
-    fn get_chained_bombs(&mut self, explosion_locations: &Vec<Coord>) -> Vec<Bomb> {
+    fn get_chained_bombs(&mut self, explosion_locations: &[Coord]) -> Vec<Bomb> {
         self.bombs.extract_if(.. , |bomb| explosion_locations.iter().any(|explosion| explosion == &bomb.position))
             .collect()
     }
@@ -152,22 +285,41 @@ impl Map {
     }
 
 
-    fn handle_exploding_bomb(&mut self, bomb: Bomb, killed_by: usize){
+    /// Explodes `bomb` and every bomb in its blast. `triggered_by` owns the bomb that started it all.
+    fn handle_exploding_bomb(&mut self, bomb: Bomb, triggered_by: usize){
         if self.grid.cell_type(bomb.position) != CellType::Wall{
             self.grid.set_cell(bomb.position, CellType::Empty);
         }
         let mut explosion_locations: Vec<Coord> = bomb.explosion_locations(self);
+        self.record_event(GameEvent::BombExploded { owner: bomb.player_id, position: bomb.position, tiles: explosion_locations.clone() });
         for tile in &explosion_locations {
-            self.grid.clear_destructable(*tile);
-            self.kill_at_location(*tile, "bomb".to_string(), killed_by);
+            if self.grid.cell_type(*tile).is_power_up() {
+                self.grid.set_cell(*tile, CellType::Empty);
+            }
+            if self.grid.clear_destructable(*tile) {
+                self.record_event(GameEvent::BlockDestroyed { position: *tile });
+                self.drop_power_up(*tile);
+            }
+            self.kill_at_location(*tile, DeathCause::Bomb { owner: bomb.player_id }, Some(triggered_by));
         }
         let chain = self.get_chained_bombs(&explosion_locations);
         for bomb in chain {
-            self.handle_exploding_bomb(bomb, killed_by)
+            self.record_event(GameEvent::ChainTriggered { owner: bomb.player_id, position: bomb.position, triggered_by });
+            self.handle_exploding_bomb(bomb, triggered_by)
         }
         self.explosions.append(&mut explosion_locations);
     }
 
+    fn drop_power_up(&mut self, location: Coord) {
+        let power_ups = &self.map_settings.power_ups;
+        if !power_ups.enabled || !self.rng.random_bool(power_ups.drop_rate.clamp(0.0, 1.0)) {
+            return;
+        }
+        let kinds = [CellType::PowerUpBomb, CellType::PowerUpRadius, CellType::PowerUpSpeed];
+        let power_up = kinds[self.rng.random_range(0..kinds.len())];
+        self.grid.set_cell(location, power_up);
+    }
+
 
 
 }
@@ -176,7 +328,9 @@ impl Map {
 mod tests {
     use super::*;
     use crate::map::player::Player;
+    use crate::map::structs::power_up_config::PowerUpConfig;
     use crate::map::grid::cell::CellType;
+    use crate::map::enums::shrink_pattern::ShrinkPattern;
 
     #[test]
     fn test_bomb_kills_player() {
@@ -186,7 +340,7 @@ mod tests {
             Player::new("P1".to_string(), Coord::from(1,1), 1),
         ];
 
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         map.add_bomb(Coord::from(1, 2), 0);
 
         //Act
@@ -194,8 +348,9 @@ mod tests {
 
         //Assert
         let p1 = map.get_player(1).expect("player 1 should exist");
-        assert_eq!(p1.is_alive(), false);
-        assert_eq!(p1.reason_killed, "bomb");
+        assert!(!p1.is_alive());
+        assert_eq!(p1.death_cause, Some(DeathCause::Bomb { owner: 0 }));
+        assert_eq!(p1.killed_by, Some(0));
     }
     #[test]
     fn test_kills_player_when_other_player_was_killed_at_same_location() {
@@ -206,21 +361,21 @@ mod tests {
             Player::new("P2".to_string(), Coord::from(1,1), 2),
             Player::new("P3".to_string(), Coord::from(1,2), 3),
         ];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         map.players.get_mut(0)
             .expect("player 1 should exist")
-            .kill(&"bomb".to_string(), 3);
+            .kill(DeathCause::Bomb { owner: 3 }, Some(3));
 
         //Act
-        map.kill_at_location(Coord::from(1, 1), "bomb".to_string(), 3);
+        map.kill_at_location(Coord::from(1, 1), DeathCause::Bomb { owner: 3 }, Some(3));
 
         //Assert
         let p1 = map.get_player(1).expect("player 1 should exist");
-        assert_eq!(p1.is_alive(), false);
+        assert!(!p1.is_alive());
         let p2 = map.get_player(2).expect("player 2 should exist");
-        assert_eq!(p2.is_alive(), false);
+        assert!(!p2.is_alive());
         let p3 = map.get_player(3).expect("player 3 should exist");
-        assert_eq!(p3.is_alive(), true);
+        assert!(p3.is_alive());
     }
     #[test]
     fn test_own_bomb_kills_player_suicide() {
@@ -230,7 +385,7 @@ mod tests {
             Player::new("P1".to_string(), Coord::from(1,1), 1),
         ];
 
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         map.add_bomb(Coord::from(1, 2), 1);
 
         //Act
@@ -238,8 +393,9 @@ mod tests {
 
         //Assert
         let p1 = map.get_player(1).expect("player 1 should exist");
-        assert_eq!(p1.is_alive(), false);
-        assert_eq!(p1.reason_killed, "suicide");
+        assert!(!p1.is_alive());
+        assert_eq!(p1.death_cause, Some(DeathCause::Suicide));
+        assert_eq!(p1.killed_by, None);
     }
 
     #[test]
@@ -247,7 +403,7 @@ mod tests {
         //Arrange
         let map_settings = MapConfig { size: 7, endgame: 0, ..Default::default() };
         let players = vec![Player::new("P1".to_string(), Coord::from(1,1), 0)];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
 
         //Act
         map.handle_shrink(map.map_settings.endgame);
@@ -255,8 +411,110 @@ mod tests {
         //Assert
         assert_eq!(map.grid.cell_type(Coord::from(1, 1)), CellType::Wall);
         let p = map.get_player(0).expect("player exists");
-        assert_eq!(p.is_alive(), false);
-        assert_eq!(p.reason_killed, "shrink");
+        assert!(!p.is_alive());
+        assert_eq!(p.death_cause, Some(DeathCause::Shrink));
+    }
+
+    #[test]
+    fn test_chain_reaction_kill_is_credited_by_policy() {
+        for (credit, killed_by, credited) in [
+            (ChainKillCredit::Trigger, 0, vec![0]),
+            (ChainKillCredit::Owner, 1, vec![1]),
+            (ChainKillCredit::Both, 1, vec![1, 0]),
+        ] {
+            //Arrange
+            let map_settings = MapConfig { size: 9, chain_kill_credit: credit, ..Default::default() };
+            let players = vec![
+                Player::new("P1".to_string(), Coord::from(7, 7), 0),
+                Player::new("P2".to_string(), Coord::from(1, 7), 1),
+                Player::new("P3".to_string(), Coord::from(4, 1), 2),
+            ];
+            let map = &mut Map::new(map_settings, players).unwrap();
+            for col in 1..=4 {
+                map.grid.set_cell(Coord::from(col, 1), CellType::Empty);
+            }
+            map.bombs.push(Bomb::new(Coord::from(1, 1), 1, 0, 1));
+            map.bombs.push(Bomb::new(Coord::from(2, 1), 5, 1, 2));
+
+            //Act
+            map.process_bombs();
+            map.record_deaths(4);
+
+            //Assert
+            let victim = map.get_player(2).expect("player 3 should exist");
+            assert_eq!(victim.death_cause, Some(DeathCause::Bomb { owner: 1 }));
+            assert_eq!(victim.killed_by, Some(killed_by));
+            assert_eq!(map.grid.cell_type(Coord::from(4, 1)), CellType::Empty);
+            assert_eq!(map.kills.len(), 1);
+            assert_eq!(map.kills[0].turn, 4);
+            assert_eq!(map.kills[0].bomb_owner, Some(1));
+            assert_eq!(map.kills[0].triggered_by, Some(0));
+            assert_eq!(map.kills[0].credited, credited);
+        }
+    }
+
+    #[test]
+    fn test_chain_reaction_records_events_in_order() {
+        //Arrange
+        let map_settings = MapConfig { size: 9, record_events: true, ..Default::default() };
+        let players = vec![
+            Player::new("P1".to_string(), Coord::from(7, 7), 0),
+            Player::new("P2".to_string(), Coord::from(1, 7), 1),
+            Player::new("P3".to_string(), Coord::from(3, 1), 2),
+        ];
+        let map = &mut Map::new(map_settings, players).unwrap();
+        map.grid.set_cell(Coord::from(1, 1), CellType::Empty);
+        map.grid.set_cell(Coord::from(1, 2), CellType::Empty);
+        map.grid.set_cell(Coord::from(2, 1), CellType::Empty);
+        map.grid.set_cell(Coord::from(3, 1), CellType::Empty);
+        map.grid.set_cell(Coord::from(4, 1), CellType::Destroyable);
+        map.bombs.push(Bomb::new(Coord::from(1, 1), 1, 0, 1));
+        map.bombs.push(Bomb::new(Coord::from(2, 1), 5, 1, 2));
+
+        //Act
+        map.process_bombs();
+
+        //Assert
+        assert_eq!(map.events, vec![
+            GameEvent::BombExploded { owner: 0, position: Coord::from(1, 1), tiles: vec![Coord::from(1, 1), Coord::from(1, 2), Coord::from(2, 1)] },
+            GameEvent::ChainTriggered { owner: 1, position: Coord::from(2, 1), triggered_by: 0 },
+            GameEvent::BombExploded { owner: 1, position: Coord::from(2, 1), tiles: vec![Coord::from(2, 1), Coord::from(1, 1), Coord::from(3, 1), Coord::from(4, 1)] },
+            GameEvent::PlayerKilled { player: 2, cause: DeathCause::Bomb { owner: 1 }, killed_by: Some(1) },
+            GameEvent::BlockDestroyed { position: Coord::from(4, 1) },
+        ]);
+    }
+
+    #[test]
+    fn test_rectangular_map_shrinks_completely_by_max_turn() {
+        //Arrange
+        let map_settings = MapConfig { width: Some(11), height: Some(7), endgame: 0, ..Default::default() };
+        let map = &mut Map::new(map_settings, vec![]).unwrap();
+
+        //Act
+        for turn in 0..map.max_turn() {
+            map.handle_shrink(turn);
+        }
+
+        //Assert
+        assert_eq!(map.max_turn(), 9 * 5);
+        assert!(map.grid.tiles.iter().all(|tile| *tile == 'W'));
+    }
+
+    #[test]
+    fn test_every_shrinking_pattern_fills_the_map_by_max_turn() {
+        for shrink in [ShrinkPattern::Rings { interval: 4 }, ShrinkPattern::Random] {
+            //Arrange
+            let map_settings = MapConfig { width: Some(9), height: Some(7), endgame: 3, shrink, ..Default::default() };
+            let map = &mut Map::new(map_settings, vec![]).unwrap();
+
+            //Act
+            for turn in 0..map.max_turn() {
+                map.handle_shrink(turn);
+            }
+
+            //Assert
+            assert!(map.grid.tiles.iter().all(|tile| *tile == 'W'), "{shrink:?}");
+        }
     }
 
     #[test]
@@ -264,12 +522,12 @@ mod tests {
         //Arrrange
         let map_settings = MapConfig { size: 7, ..Default::default() };
         let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         for col in 1..5{
             map.grid.clear_destructable(Coord::from(col, 5));
         }
 
-        map.bombs.push(Bomb::new(Coord::from(1, 5), 0,0));
+        map.bombs.push(Bomb::new(Coord::from(1, 5), 0, 0, 2));
         map.add_bomb(Coord::from(3, 5), 0);
         map.add_bomb(Coord::from(5, 5), 0);
 
@@ -285,11 +543,11 @@ mod tests {
         //Arrrange
         let map_settings = MapConfig { size: 7, ..Default::default() };
         let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         for col in 1..5{
             map.grid.clear_destructable(Coord::from(col, 5));
         }
-        map.bombs.push(Bomb::new(Coord::from(1, 5), 0,0));
+        map.bombs.push(Bomb::new(Coord::from(1, 5), 0, 0, 2));
         map.add_bomb(Coord::from(5, 5), 0);
 
         //Act
@@ -305,7 +563,7 @@ mod tests {
         //Arrange
         let map_settings = MapConfig { size: 7, ..Default::default() };
         let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         map.add_bomb(Coord::from(3, 3), 0);
 
         //Act
@@ -320,7 +578,7 @@ mod tests {
         //Arrange
         let map_settings = MapConfig { size: 7, ..Default::default() };
         let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
 
         map.add_bomb(Coord::from(1, 2), 0);
         map.add_bomb(Coord::from(2, 1), 0);
@@ -338,7 +596,7 @@ mod tests {
         //Arrange
         let map_settings = MapConfig { size: 7, ..Default::default() };
         let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
 
         map.add_bomb(Coord::from(1, 2), 0);
 
@@ -355,15 +613,15 @@ mod tests {
             Player::new("P1".to_string(), Coord::from(1, 2), 0),
             Player::new("P2".to_string(), Coord::from(2, 1), 1),
         ];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
         map.add_bomb(Coord::from(1, 1), 0);
 
         //Act
         map.process_bombs();
 
         //Assert
-        assert_eq!(map.get_player(0).unwrap().is_alive(), false);
-        assert_eq!(map.get_player(1).unwrap().is_alive(), false);
+        assert!(!map.get_player(0).unwrap().is_alive());
+        assert!(!map.get_player(1).unwrap().is_alive());
     }
 
     #[test]
@@ -374,14 +632,118 @@ mod tests {
             Player::new("P1".to_string(), Coord::from(2, 2), 0),
             Player::new("P2".to_string(), Coord::from(4, 4), 1),
         ];
-        let map = &mut Map::new(map_settings, players);
+        let map = &mut Map::new(map_settings, players).unwrap();
 
         map.add_bomb(Coord::from(2, 2), 0);
         map.add_bomb(Coord::from(4, 4), 1);
 
         map.process_bombs();
 
-        assert_eq!(map.get_player(0).unwrap().is_alive(), false);
-        assert_eq!(map.get_player(1).unwrap().is_alive(), false);
+        assert!(!map.get_player(0).unwrap().is_alive());
+        assert!(!map.get_player(1).unwrap().is_alive());
+    }
+
+    #[test]
+    fn test_update_outcome_is_draw_when_last_players_die_same_turn() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, bomb_timer: 0, bomb_radius: 1, ..Default::default() };
+        let players = vec![
+            Player::new("P1".to_string(), Coord::from(1, 2), 0),
+            Player::new("P2".to_string(), Coord::from(2, 1), 1),
+        ];
+        let map = &mut Map::new(map_settings, players).unwrap();
+        map.add_bomb(Coord::from(1, 1), 0);
+        map.process_bombs();
+
+        //Act
+        map.update_outcome();
+
+        //Assert
+        assert_eq!(map.outcome(), GameOutcome::Draw);
+        assert!(map.is_game_over());
+        assert!(!map.has_winner());
+    }
+
+    #[test]
+    fn test_update_outcome_sets_winner_when_one_player_left() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, bomb_timer: 0, bomb_radius: 1, ..Default::default() };
+        let players = vec![
+            Player::new("P1".to_string(), Coord::from(1, 2), 0),
+            Player::new("P2".to_string(), Coord::from(5, 5), 1),
+        ];
+        let map = &mut Map::new(map_settings, players).unwrap();
+        map.add_bomb(Coord::from(1, 1), 0);
+        map.process_bombs();
+
+        //Act
+        map.update_outcome();
+
+        //Assert
+        assert_eq!(map.outcome(), GameOutcome::Winner(1));
+    }
+
+    #[test]
+    fn test_destroyed_tile_drops_power_up_when_enabled() {
+        //Arrange
+        let power_ups = PowerUpConfig { enabled: true, drop_rate: 1.0 };
+        let map_settings = MapConfig { size: 7, bomb_timer: 0, power_ups, ..Default::default() };
+        let players = vec![Player::new("P1".to_string(), Coord::from(5, 5), 0)];
+        let map = &mut Map::new(map_settings, players).unwrap();
+        map.add_bomb(Coord::from(3, 3), 0);
+
+        //Act
+        map.process_bombs();
+
+        //Assert
+        assert!(map.grid.cell_type(Coord::from(3, 2)).is_power_up());
+        assert!(map.grid.cell_type(Coord::from(2, 3)).is_power_up());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_destroyed_tile_drops_nothing_by_default() {
+        //Arrange
+        let map_settings = MapConfig { size: 7, bomb_timer: 0, ..Default::default() };
+        let players = vec![Player::new("P1".to_string(), Coord::from(5, 5), 0)];
+        let map = &mut Map::new(map_settings, players).unwrap();
+        map.add_bomb(Coord::from(3, 3), 0);
+
+        //Act
+        map.process_bombs();
+
+        //Assert
+        assert_eq!(map.grid.cell_type(Coord::from(3, 2)), CellType::Empty);
+    }
+
+    #[test]
+    fn test_bomb_capacity() {
+        //Arrange
+        let power_ups = PowerUpConfig { enabled: true, drop_rate: 0.0 };
+        let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
+        let unlimited = Map::new(MapConfig { size: 7, ..Default::default() }, players.clone()).unwrap();
+        let limited = Map::new(MapConfig { size: 7, max_bombs_per_player: Some(2), ..Default::default() }, players.clone()).unwrap();
+        let mut with_power_ups = Map::new(MapConfig { size: 7, power_ups, ..Default::default() }, players).unwrap();
+
+        //Act
+        with_power_ups.players[0].stats.collect(CellType::PowerUpBomb);
+
+        //Assert
+        assert_eq!(unlimited.bomb_capacity(0), None);
+        assert_eq!(limited.bomb_capacity(0), Some(2));
+        assert_eq!(with_power_ups.bomb_capacity(0), Some(2));
+    }
+
+    #[test]
+    fn test_radius_power_up_enlarges_new_bombs() {
+        //Arrange
+        let players = vec![Player::new("P1".to_string(), Coord::from(1, 1), 0)];
+        let map = &mut Map::new(MapConfig { size: 7, ..Default::default() }, players).unwrap();
+        map.players[0].stats.collect(CellType::PowerUpRadius);
+
+        //Act
+        map.add_bomb(Coord::from(1, 1), 0);
+
+        //Assert
+        assert_eq!(map.bombs[0].radius, map.map_settings.bomb_radius + 1);
+    }
+}
@@ -1,6 +1,7 @@
-use std::usize;
 
 use crate::coord::Coord;
+use crate::map::enums::death_cause::DeathCause;
+use crate::map::structs::player_stats::PlayerStats;
 
 #[derive(Clone, Debug)]
 pub struct Player {
@@ -8,8 +9,12 @@ pub struct Player {
     pub position: Coord,
     pub id: usize,
     alive: bool,
-    pub reason_killed: String,
-    pub killed_by: usize
+    /// `None` while the player is alive.
+    pub death_cause: Option<DeathCause>,
+    /// The player credited for the kill, `None` when nobody is.
+    pub killed_by: Option<usize>,
+    pub death_turn: Option<usize>,
+    pub stats: PlayerStats,
 }
 
 impl Player {
@@ -19,8 +24,10 @@ impl Player {
             position,
             id,
             alive: true,
-            reason_killed: "".to_string(),
-            killed_by: usize::MAX
+            death_cause: None,
+            killed_by: None,
+            death_turn: None,
+            stats: PlayerStats::default(),
         }
     }
 
@@ -32,13 +39,9 @@ impl Player {
         self.alive
     }
 
-    pub(crate) fn kill(&mut self, reason_killed: &String, killed_by: usize) {
+    pub(crate) fn kill(&mut self, cause: DeathCause, killed_by: Option<usize>) {
         self.alive = false;
-        if killed_by == self.id {
-            self.reason_killed = "suicide".to_string()
-        }else {
-            self.reason_killed = reason_killed.clone();
-        }
+        self.death_cause = Some(cause);
         self.killed_by = killed_by;
     }
 }
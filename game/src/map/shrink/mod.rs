@@ -0,0 +1,5 @@
+pub mod no_shrink;
+pub mod random_shrink;
+pub mod ring_shrink;
+pub mod spiral_shrink;
+pub mod traits;
@@ -0,0 +1,18 @@
+use crate::coord::Coord;
+use crate::map::shrink::traits::shrink_strategy::ShrinkStrategy;
+
+/// Sudden death: the map never shrinks, and a game that is still running after `duration` steps
+/// ends in a draw.
+pub struct NoShrink {
+    pub duration: usize,
+}
+
+impl ShrinkStrategy for NoShrink {
+    fn cells(&self, _step: usize) -> Vec<Coord> {
+        Vec::new()
+    }
+
+    fn duration(&self) -> usize {
+        self.duration
+    }
+}
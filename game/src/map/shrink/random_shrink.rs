@@ -0,0 +1,52 @@
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use crate::coord::Coord;
+use crate::map::shrink::traits::shrink_strategy::ShrinkStrategy;
+
+/// Collapses one random inner cell per step. The order comes from the map seed, so a replay
+/// collapses the same cells.
+pub struct RandomShrink {
+    order: Vec<Coord>,
+}
+
+impl RandomShrink {
+    pub fn new(width: usize, height: usize, seed: u64) -> Self {
+        let mut order: Vec<Coord> = (1..height - 1)
+            .flat_map(|row| (1..width - 1).map(move |col| Coord::from(col, row)))
+            .collect();
+        order.shuffle(&mut StdRng::seed_from_u64(seed));
+        Self { order }
+    }
+}
+
+impl ShrinkStrategy for RandomShrink {
+    fn cells(&self, step: usize) -> Vec<Coord> {
+        self.order.get(step).copied().into_iter().collect()
+    }
+
+    fn duration(&self) -> usize {
+        self.order.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_random_shrink_collapses_every_inner_cell_once() {
+        //Arrange
+        let shrink = RandomShrink::new(9, 7, 42);
+
+        //Act
+        let cells: Vec<Coord> = (0..shrink.duration()).flat_map(|step| shrink.cells(step)).collect();
+
+        //Assert
+        assert_eq!(cells.len(), 7 * 5);
+        assert!(cells.iter().all(|cell| (1..8).contains(&cell.col.get()) && (1..6).contains(&cell.row.get())));
+        assert!(cells.iter().enumerate().all(|(i, cell)| !cells[..i].contains(cell)));
+        assert_eq!(cells, (0..35).flat_map(|step| RandomShrink::new(9, 7, 42).cells(step)).collect::<Vec<_>>());
+        assert!(shrink.cells(35).is_empty());
+    }
+}
@@ -0,0 +1,66 @@
+use crate::coord::Coord;
+use crate::map::shrink::traits::shrink_strategy::ShrinkStrategy;
+
+/// Closes the outermost open ring all at once every `interval` steps.
+pub struct RingShrink {
+    width: usize,
+    height: usize,
+    interval: usize,
+}
+
+impl RingShrink {
+    pub fn new(width: usize, height: usize, interval: usize) -> Self {
+        Self { width, height, interval: interval.max(1) }
+    }
+
+    fn ring_count(&self) -> usize {
+        (self.width - 2).min(self.height - 2).div_ceil(2)
+    }
+
+    /// Distance of an inner cell to the outer wall, 0 for the cells right next to it.
+    fn ring_of(&self, col: usize, row: usize) -> usize {
+        (col - 1).min(row - 1).min(self.width - 2 - col).min(self.height - 2 - row)
+    }
+}
+
+impl ShrinkStrategy for RingShrink {
+    fn cells(&self, step: usize) -> Vec<Coord> {
+        if !step.is_multiple_of(self.interval) {
+            return Vec::new();
+        }
+        let ring = step / self.interval;
+        (1..self.height - 1)
+            .flat_map(|row| (1..self.width - 1).map(move |col| (col, row)))
+            .filter(|&(col, row)| self.ring_of(col, row) == ring)
+            .map(|(col, row)| Coord::from(col, row))
+            .collect()
+    }
+
+    fn duration(&self) -> usize {
+        (self.ring_count() - 1) * self.interval + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ring_shrink_closes_rings_every_interval() {
+        //Arrange
+        let shrink = RingShrink::new(9, 7, 5);
+
+        //Act
+        let first = shrink.cells(0);
+        let between = shrink.cells(3);
+        let last = shrink.cells(10);
+
+        //Assert
+        assert_eq!(first.len(), 2 * 7 + 2 * 3);
+        assert!(first.contains(&Coord::from(1, 1)) && first.contains(&Coord::from(7, 5)));
+        assert!(between.is_empty());
+        assert_eq!(last, vec![Coord::from(3, 3), Coord::from(4, 3), Coord::from(5, 3)]);
+        assert_eq!(shrink.duration(), 11);
+        assert_eq!((0..shrink.duration()).map(|step| shrink.cells(step).len()).sum::<usize>(), 7 * 5);
+    }
+}
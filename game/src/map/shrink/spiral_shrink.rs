@@ -0,0 +1,128 @@
+use crate::coord::Coord;
+use crate::map::shrink::traits::shrink_strategy::ShrinkStrategy;
+
+/// Walls in one cell per step, in a clockwise spiral from the top-left corner to the centre.
+pub struct SpiralShrink {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl ShrinkStrategy for SpiralShrink {
+    fn cells(&self, step: usize) -> Vec<Coord> {
+        calculate_shrink_location(step, self.width, self.height).into_iter().collect()
+    }
+
+    fn duration(&self) -> usize {
+        (self.width - 2) * (self.height - 2)
+    }
+}
+
+pub fn calculate_shrink_location(
+    shrink_number: usize,
+    width: usize,
+    height: usize
+) -> Option<Coord> {
+    if width < 3 || height < 3 || shrink_number >= (width - 2) * (height - 2) {
+        return None;
+    }
+    spiral_coord(width - 2, height - 2, shrink_number, 0)
+}
+
+fn spiral_coord(width: usize, height: usize, mut n: usize, layer: usize) -> Option<Coord> {
+    if width == 0 || height == 0 {
+        return None;
+    } else if width == 1 {
+        return get_coord_with_offset(layer, layer + n);
+    } else if height == 1 {
+        return get_coord_with_offset(layer + n, layer);
+    }
+    let perimiter = calculate_perimiter(width, height);
+    if n < perimiter {
+        let start = layer;
+        let right = layer + width - 1;
+        let bottom = layer + height - 1;
+        if is_top_row(n, width) {
+            get_coord_with_offset(start + n, start)
+        } else if is_right_column(n, width, height) {
+            n -= width - 1;
+            get_coord_with_offset(right, start + n)
+        } else if is_bottom_row(n, width, height) {
+            n -= (width - 1) + (height - 1);
+            get_coord_with_offset(right - n, bottom)
+        } else {
+            n -= 2 * (width - 1) + (height - 1);
+            get_coord_with_offset(start, bottom - n)
+        }
+    }else {
+        spiral_coord(width - 2, height - 2, n - perimiter, layer + 1)
+    }
+}
+
+#[inline(always)]
+fn calculate_perimiter(width: usize, height: usize) -> usize {
+    2 * (width - 1) + 2 * (height - 1)
+}
+
+#[inline(always)]
+fn is_top_row(n: usize, width: usize) -> bool {
+    n < width - 1
+}
+
+#[inline(always)]
+fn is_right_column(n: usize, width: usize, height: usize) -> bool {
+    n < (width - 1) + (height - 1)
+}
+
+#[inline(always)]
+fn is_bottom_row(n: usize, width: usize, height: usize) -> bool {
+   n < 2 * (width - 1) + (height - 1)
+}
+
+#[inline(always)]
+fn get_coord_with_offset(col: usize, row: usize) -> Option<Coord> {
+    Some(Coord::from(col + 1, row + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shrink_location() {
+        assert_eq!(calculate_shrink_location(0, 5, 5), Some(Coord::from(1, 1))); // Top-left corner
+        assert_eq!(calculate_shrink_location(1, 5, 5), Some(Coord::from(2, 1))); // Top row
+        assert_eq!(calculate_shrink_location(2, 5, 5), Some(Coord::from(3, 1)));
+        assert_eq!(calculate_shrink_location(3, 5, 5), Some(Coord::from(3, 2)));
+        assert_eq!(calculate_shrink_location(4, 5, 5), Some(Coord::from(3, 3)));
+        assert_eq!(calculate_shrink_location(5, 5, 5), Some(Coord::from(2, 3)));
+        assert_eq!(calculate_shrink_location(6, 5, 5), Some(Coord::from(1, 3)));
+        assert_eq!(calculate_shrink_location(7, 5, 5), Some(Coord::from(1, 2)));
+        assert_eq!(calculate_shrink_location(8, 5, 5), Some(Coord::from(2, 2))); // center
+
+        // moet fail of none opleveren
+        assert_eq!(calculate_shrink_location(25, 7, 7), None);
+    }
+
+    #[test]
+    fn test_shrink_location_rectangular_visits_every_inner_cell_once() {
+        //Arrange
+        let (width, height) = (9, 5);
+
+        //Act
+        let locations: Vec<Coord> = (0..(width - 2) * (height - 2))
+            .map(|n| calculate_shrink_location(n, width, height).unwrap())
+            .collect();
+
+        //Assert
+        assert_eq!(locations[0], Coord::from(1, 1));
+        assert_eq!(locations[6], Coord::from(7, 1));
+        assert_eq!(locations[8], Coord::from(7, 3));
+        assert_eq!(*locations.last().unwrap(), Coord::from(6, 2));
+        for row in 1..height - 1 {
+            for col in 1..width - 1 {
+                assert_eq!(locations.iter().filter(|c| **c == Coord::from(col, row)).count(), 1);
+            }
+        }
+        assert_eq!(calculate_shrink_location(21, width, height), None);
+    }
+}
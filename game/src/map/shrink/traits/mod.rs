@@ -0,0 +1 @@
+pub mod shrink_strategy;
@@ -0,0 +1,10 @@
+use crate::coord::Coord;
+
+/// Decides which cells become wall once the endgame has started.
+pub trait ShrinkStrategy {
+    /// Cells that become wall at the end of shrink step `step`, where step 0 is the endgame turn.
+    fn cells(&self, step: usize) -> Vec<Coord>;
+
+    /// Number of steps after which the map is done shrinking, so the game cannot last longer.
+    fn duration(&self) -> usize;
+}
@@ -0,0 +1,18 @@
+use serde::{Deserialize, Serialize};
+use crate::map::enums::death_cause::DeathCause;
+
+/// A player that died, with everyone involved in the kill.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KillRecord {
+    pub turn: usize,
+    pub victim: usize,
+    pub cause: DeathCause,
+    /// Owner of the bomb whose blast hit the victim.
+    pub bomb_owner: Option<usize>,
+    /// Owner of the bomb that started the chain reaction, the same as `bomb_owner` when the bomb
+    /// went off by itself.
+    pub triggered_by: Option<usize>,
+    /// Players that get credit for the kill according to `MapConfig::chain_kill_credit`. Never
+    /// contains the victim, so it is empty for a suicide.
+    pub credited: Vec<usize>,
+}
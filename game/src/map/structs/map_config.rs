@@ -1,21 +1,110 @@
 use serde::{Deserialize, Serialize};
+use crate::coord::Coord;
+use crate::map::enums::chain_kill_credit::ChainKillCredit;
+use crate::map::enums::move_resolution::MoveResolution;
+use crate::map::enums::shrink_pattern::ShrinkPattern;
+use crate::map::structs::map_generation_config::MapGenerationConfig;
+use crate::map::structs::map_layout::MapLayout;
+use crate::map::structs::power_up_config::PowerUpConfig;
+use crate::map::structs::time_budget::TimeBudget;
 
 #[allow(dead_code)]
 #[derive(Clone, Serialize, Deserialize)]
 pub struct MapConfig {
+    /// Width and height of a square map.
     pub size: usize,
+    /// Width of a rectangular map, `size` when not set.
+    #[serde(default)]
+    pub width: Option<usize>,
+    /// Height of a rectangular map, `size` when not set.
+    #[serde(default)]
+    pub height: Option<usize>,
     pub bomb_timer: usize,
     pub bomb_radius: usize,
     pub endgame: usize,
+    /// Seed for everything random in a game, so a game can be reproduced.
+    #[serde(default)]
+    pub seed: u64,
+    #[serde(default)]
+    pub time_budget: TimeBudget,
+    #[serde(default)]
+    pub power_ups: PowerUpConfig,
+    /// Bombs a player can have on the map at once, before power-ups. `None` means no limit,
+    /// unless power-ups are enabled, which start every player at one bomb.
+    #[serde(default)]
+    pub max_bombs_per_player: Option<usize>,
+    /// Enables `Command::KickBomb`.
+    #[serde(default)]
+    pub allow_kick: bool,
+    /// Enables `Command::Detonate`.
+    #[serde(default)]
+    pub allow_detonate: bool,
+    /// Hand-designed arena to play on instead of the generated pillar grid. Its size must match the map size.
+    #[serde(default)]
+    pub layout: Option<MapLayout>,
+    /// Generates a random map from `seed` instead of the fixed pillar grid. Ignored when `layout` is set.
+    #[serde(default)]
+    pub generation: Option<MapGenerationConfig>,
+    /// How the map closes in from `endgame` on.
+    #[serde(default)]
+    pub shrink: ShrinkPattern,
+    /// Whether players move one after the other or all at once.
+    #[serde(default)]
+    pub move_resolution: MoveResolution,
+    /// Who is credited when a chain reaction kills a player.
+    #[serde(default)]
+    pub chain_kill_credit: ChainKillCredit,
+    /// Keeps a log of everything that happens each turn in the game result.
+    #[serde(default)]
+    pub record_events: bool,
 }
 
 impl Default for MapConfig {
     fn default() -> Self {
         Self {
             size: 15,
+            width: None,
+            height: None,
             bomb_timer: 3,
             bomb_radius: 2,
             endgame: 100,
+            seed: 0,
+            time_budget: TimeBudget::default(),
+            power_ups: PowerUpConfig::default(),
+            max_bombs_per_player: None,
+            allow_kick: false,
+            allow_detonate: false,
+            layout: None,
+            generation: None,
+            shrink: ShrinkPattern::Spiral,
+            move_resolution: MoveResolution::Sequential,
+            chain_kill_credit: ChainKillCredit::Owner,
+            record_events: false,
         }
     }
 }
+
+impl MapConfig {
+    pub fn width(&self) -> usize {
+        self.width.unwrap_or(self.size)
+    }
+
+    pub fn height(&self) -> usize {
+        self.height.unwrap_or(self.size)
+    }
+
+    /// Where the players start, in player order: the layout spawns, or else the four corners.
+    pub fn spawn_positions(&self) -> Vec<Coord> {
+        if let Some(layout) = &self.layout {
+            return layout.spawns.clone();
+        }
+        // Saturating, so an unvalidated tiny map gives odd spawns instead of a panic
+        let (width, height) = (self.width(), self.height());
+        vec![
+            Coord::from(1, 1),
+            Coord::from(1, height.saturating_sub(2)),
+            Coord::from(width.saturating_sub(2), 1),
+            Coord::from(width.saturating_sub(2), height.saturating_sub(2)),
+        ]
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+use crate::map::enums::symmetry::Symmetry;
+
+/// Settings for a randomly generated map, which is derived from the map seed.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MapGenerationConfig {
+    /// Chance between 0 and 1 that a free cell starts as destroyable.
+    pub destroyable_density: f64,
+    /// Chance between 0 and 1 that a free cell becomes an extra wall. Cells near the spawns stay free.
+    pub obstacle_density: f64,
+    pub symmetry: Symmetry,
+}
+
+impl Default for MapGenerationConfig {
+    fn default() -> Self {
+        Self {
+            destroyable_density: 0.8,
+            obstacle_density: 0.1,
+            symmetry: Symmetry::Mirror,
+        }
+    }
+}
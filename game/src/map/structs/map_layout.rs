@@ -0,0 +1,151 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use serde::{Deserialize, Serialize};
+use crate::coord::Coord;
+use crate::map::grid::cell::CellType;
+
+/// A hand-designed arena, loaded from a map file.
+///
+/// A map file starts with optional `key: value` metadata lines, followed by the grid. Every grid
+/// line is one row, all of the same length, written with the `CellType::as_char` characters, where `1` to `9` mark the
+/// spawn of the player with that number. Spawns start empty. Lines starting with `//` are comments.
+///
+/// ```text
+/// name: Tiny
+/// // Empty cells are spaces, so keep trailing spaces in the file
+/// WWWWWWW
+/// W1 . 2W
+/// W W.W W
+/// W..r..W
+/// W W.W W
+/// W3 . 4W
+/// WWWWWWW
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MapLayout {
+    pub metadata: BTreeMap<String, String>,
+    pub width: usize,
+    pub height: usize,
+    /// All cells row by row, so `row * width + col`.
+    pub tiles: Vec<CellType>,
+    /// Spawn of every player, in player order.
+    pub spawns: Vec<Coord>,
+}
+
+impl MapLayout {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut metadata = BTreeMap::new();
+        let mut rows: Vec<(usize, &str)> = Vec::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.strip_suffix('\r').unwrap_or(line);
+            if line.starts_with("//") || (line.is_empty() && rows.is_empty()) {
+                continue;
+            }
+            if rows.is_empty() && let Some((key, value)) = line.split_once(':') {
+                metadata.insert(key.trim().to_string(), value.trim().to_string());
+                continue;
+            }
+            rows.push((index + 1, line));
+        }
+        while rows.last().is_some_and(|(_, line)| line.is_empty()) {
+            rows.pop();
+        }
+
+        let height = rows.len();
+        let Some(width) = rows.first().map(|(_, line)| line.chars().count()) else {
+            return Err("Map file has no grid".to_string());
+        };
+        let mut tiles = Vec::with_capacity(width * height);
+        let mut numbered_spawns = Vec::new();
+        for (row, (line_number, line)) in rows.iter().enumerate() {
+            let cells = line.chars().count();
+            if cells != width {
+                return Err(format!("Line {line_number} has {cells} cells, the first row has {width}"));
+            }
+            for (col, c) in line.chars().enumerate() {
+                let cell = match c {
+                    '1'..='9' => {
+                        numbered_spawns.push((c, Coord::from(col, row)));
+                        CellType::Empty
+                    }
+                    ' ' | 'W' | '.' | 'b' | 'r' | 's' => CellType::from_char(c),
+                    _ => return Err(format!("Line {line_number} has an invalid cell '{c}' in column {}", col + 1)),
+                };
+                tiles.push(cell);
+            }
+        }
+
+        numbered_spawns.sort_by_key(|(number, _)| *number);
+        for (expected, (number, _)) in ('1'..='9').zip(&numbered_spawns) {
+            if *number < expected {
+                return Err(format!("Spawn {number} is used more than once"));
+            }
+            if *number > expected {
+                return Err(format!("Spawn {expected} is missing"));
+            }
+        }
+
+        Ok(MapLayout {
+            metadata,
+            width,
+            height,
+            tiles,
+            spawns: numbered_spawns.into_iter().map(|(_, coord)| coord).collect(),
+        })
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let text = std::fs::read_to_string(path).map_err(|err| format!("Cannot read map {}: {err}", path.display()))?;
+        Self::parse(&text).map_err(|err| format!("Invalid map {}: {err}", path.display()))
+    }
+
+    /// The `name` from the metadata, or "Custom" when the file has none.
+    pub fn name(&self) -> &str {
+        self.metadata.get("name").map_or("Custom", String::as_str)
+    }
+
+    pub fn cell(&self, coord: Coord) -> CellType {
+        if !coord.is_valid(self.width, self.height) {
+            return CellType::Wall;
+        }
+        self.tiles[coord.row.get() * self.width + coord.col.get()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TINY: &str = "name: Tiny\nauthor: Someone\n// a comment\nWWWWWWW\nW1 . 2W\nW W.W W\nW..r..W\nW W.W W\nW3 . 4W\nWWWWWWW\n\n";
+
+    #[test]
+    fn test_parse_reads_metadata_cells_and_spawns() {
+        //Act
+        let layout = MapLayout::parse(TINY).unwrap();
+
+        //Assert
+        assert_eq!(layout.name(), "Tiny");
+        assert_eq!(layout.metadata["author"], "Someone");
+        assert_eq!((layout.width, layout.height), (7, 7));
+        assert_eq!(layout.spawns, vec![Coord::from(1, 1), Coord::from(5, 1), Coord::from(1, 5), Coord::from(5, 5)]);
+        assert_eq!(layout.cell(Coord::from(1, 1)), CellType::Empty);
+        assert_eq!(layout.cell(Coord::from(3, 1)), CellType::Destroyable);
+        assert_eq!(layout.cell(Coord::from(3, 3)), CellType::PowerUpRadius);
+        assert_eq!(layout.cell(Coord::from(0, 3)), CellType::Wall);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_grids() {
+        //Arrange
+        let ragged = "WWW\nW1\nWWW";
+        let invalid_cell = "WWW\nWPW\nWWW";
+        let missing_spawn = "WWWW\nW13W\nW  W\nWWWW";
+
+        //Act & Assert
+        assert!(MapLayout::parse(ragged).unwrap_err().contains("Line 2"));
+        assert!(MapLayout::parse(invalid_cell).unwrap_err().contains("'P'"));
+        assert!(MapLayout::parse(missing_spawn).unwrap_err().contains("Spawn 2"));
+        assert!(MapLayout::parse("name: Empty").is_err());
+    }
+}
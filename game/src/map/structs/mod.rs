@@ -1 +1,7 @@
-pub mod map_config;
\ No newline at end of file
+pub mod kill_record;
+pub mod map_config;
+pub mod map_generation_config;
+pub mod map_layout;
+pub mod player_stats;
+pub mod power_up_config;
+pub mod time_budget;
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use crate::map::grid::cell::CellType;
+
+/// What a player has gained from power-ups, on top of the `MapConfig` settings.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerStats {
+    /// Bombs a player can have on the map at once, on top of the first one.
+    pub extra_bombs: usize,
+    pub extra_radius: usize,
+    /// Tiles a player moves per turn, on top of the first one.
+    pub extra_speed: usize,
+}
+
+impl PlayerStats {
+    pub fn collect(&mut self, power_up: CellType) {
+        match power_up {
+            CellType::PowerUpBomb => self.extra_bombs += 1,
+            CellType::PowerUpRadius => self.extra_radius += 1,
+            CellType::PowerUpSpeed => self.extra_speed += 1,
+            _ => {}
+        }
+    }
+}
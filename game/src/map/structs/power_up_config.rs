@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Power-ups that can drop from destroyed tiles. Off by default, so the classic ruleset stays
+/// the default.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PowerUpConfig {
+    pub enabled: bool,
+    /// Chance between 0 and 1 that a destroyed tile drops a power-up.
+    pub drop_rate: f64,
+}
+
+impl Default for PowerUpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            drop_rate: 0.3,
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use std::time::Duration;
+use serde::{Deserialize, Serialize};
+
+/// What happens to a bot that goes over its time budget.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TimeoutPolicy {
+    /// The move is replaced by `Command::Wait`.
+    #[default]
+    Wait,
+    /// The bot forfeits and its player is removed from the game.
+    Forfeit,
+}
+
+/// Time a bot may spend thinking. Limits are off by default, because timing makes games
+/// depend on the machine they run on. A timed game is no longer reproducible from its seed, its
+/// `replay_data` still replays it.
+///
+/// The time is measured after `get_move` returns. Interrupting an in-process bot that never
+/// returns is out of scope: it hangs its game and the tournament thread playing it. Untrusted bots
+/// should run as `ExternalBot` or `WasmBot`, which stop a hanging bot with a response timeout or a
+/// fuel limit of their own.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TimeBudget {
+    /// Maximum time for a single `get_move` call.
+    pub per_move: Option<Duration>,
+    /// Maximum time for all `get_move` calls of one game. Once used up, the bot is no longer asked for moves.
+    pub per_game: Option<Duration>,
+    pub on_timeout: TimeoutPolicy,
+}
+
+impl TimeBudget {
+    pub fn move_exceeded(&self, elapsed: Duration) -> bool {
+        self.per_move.is_some_and(|limit| elapsed > limit)
+    }
+
+    pub fn game_exceeded(&self, used: Duration) -> bool {
+        self.per_game.is_some_and(|limit| used > limit)
+    }
+}
@@ -1,19 +1,59 @@
+use crate::map::enums::map_config_error::MapConfigError;
 use crate::map::structs::map_config::MapConfig;
+use crate::map::validators::map_validator::map_validator_node::MapValidatorNode;
 use crate::map::validators::map_validator::traits::map_validator::MapValidator;
+use crate::map::validators::map_validator::validator::bomb_settings_validator::validate_bomb_settings;
+use crate::map::validators::map_validator::validator::endgame_validator::validate_endgame;
+use crate::map::validators::map_validator::validator::generation_validator::validate_generation;
+use crate::map::validators::map_validator::validator::map_layout_validator::MapLayoutValidator;
 use crate::map::validators::map_validator::validator::map_size_validator::MapSizeValidator;
+use crate::map::validators::map_validator::validator::spawn_count_validator::validate_spawn_count;
+use crate::map::validators::map_validator::validator::spawn_reachability_validator::SpawnReachabilityValidator;
 
 pub struct MapValidatorChainFactory;
 
 impl MapValidatorChainFactory {
     pub fn create() -> Box<dyn MapValidator> {
-        // Start with the first validator
-        let chain: Box<dyn MapValidator> = Box::new(MapSizeValidator::new());
+        // The size goes first, the later validators rely on a sane width and height
+        let validators: Vec<Box<dyn MapValidator>> = vec![
+            Box::new(MapSizeValidator::new()),
+            Box::new(MapValidatorNode::new(validate_bomb_settings)),
+            Box::new(MapValidatorNode::new(validate_endgame)),
+            Box::new(MapValidatorNode::new(validate_generation)),
+            Box::new(MapLayoutValidator::new()),
+            Box::new(MapValidatorNode::new(validate_spawn_count)),
+            Box::new(SpawnReachabilityValidator::new()),
+        ];
 
-        chain
+        validators
+            .into_iter()
+            .rev()
+            .reduce(|next, validator| validator.set_next(next))
+            .expect("The chain has validators")
     }
 
-    pub fn validate(config: &MapConfig) -> Result<(), String> {
+    /// Checks whether `config` can be played by `player_count` players.
+    pub fn validate(config: &MapConfig, player_count: usize) -> Result<(), MapConfigError> {
         let chain = Self::create();
-        chain.validate(config)
+        chain.validate(config, player_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chain_reports_first_problem() {
+        //Arrange
+        let valid = MapConfig::default();
+        let bad_size_and_timer = MapConfig { size: 8, bomb_timer: 11, ..MapConfig::default() };
+        let bad_timer = MapConfig { bomb_timer: 11, ..MapConfig::default() };
+
+        //Act & Assert
+        assert!(MapValidatorChainFactory::validate(&valid, 4).is_ok());
+        assert!(matches!(MapValidatorChainFactory::validate(&bad_size_and_timer, 4), Err(MapConfigError::InvalidSize { .. })));
+        assert!(matches!(MapValidatorChainFactory::validate(&bad_timer, 4), Err(MapConfigError::BombTimerOutOfRange { .. })));
+        assert!(matches!(MapValidatorChainFactory::validate(&valid, 5), Err(MapConfigError::NotEnoughSpawns { .. })));
     }
 }
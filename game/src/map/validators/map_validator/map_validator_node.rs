@@ -1,13 +1,14 @@
+use crate::map::enums::map_config_error::MapConfigError;
 use crate::map::structs::map_config::MapConfig;
 use crate::map::validators::map_validator::traits::map_validator::MapValidator;
 
 pub struct MapValidatorNode {
     next: Option<Box<dyn MapValidator>>,
-    validate_fn: fn(&MapConfig) -> Result<(), String>,
+    validate_fn: fn(&MapConfig, usize) -> Result<(), MapConfigError>,
 }
 
 impl MapValidatorNode {
-    pub fn new(validate_fn: fn(&MapConfig) -> Result<(), String>) -> Self {
+    pub fn new(validate_fn: fn(&MapConfig, usize) -> Result<(), MapConfigError>) -> Self {
         MapValidatorNode { next: None, validate_fn }
     }
 }
@@ -18,10 +19,10 @@ impl MapValidator for MapValidatorNode {
         self
     }
 
-    fn validate(&self, config: &MapConfig) -> Result<(), String> {
-        (self.validate_fn)(config)?;
+    fn validate(&self, config: &MapConfig, player_count: usize) -> Result<(), MapConfigError> {
+        (self.validate_fn)(config, player_count)?;
         if let Some(ref next_validator) = self.next {
-            next_validator.validate(config)
+            next_validator.validate(config, player_count)
         } else {
             Ok(())
         }
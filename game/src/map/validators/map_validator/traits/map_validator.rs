@@ -1,6 +1,8 @@
+use crate::map::enums::map_config_error::MapConfigError;
 use crate::map::structs::map_config::MapConfig;
 
 pub trait MapValidator {
     fn set_next(self: Box<Self>, next: Box<dyn MapValidator>) -> Box<dyn MapValidator>;
-    fn validate(&self, config: &MapConfig) -> Result<(), String>;
+    /// Checks `config` for a game with `player_count` players.
+    fn validate(&self, config: &MapConfig, player_count: usize) -> Result<(), MapConfigError>;
 }
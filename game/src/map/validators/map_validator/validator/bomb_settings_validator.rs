@@ -0,0 +1,38 @@
+use std::ops::RangeInclusive;
+use crate::map::enums::map_config_error::MapConfigError;
+use crate::map::structs::map_config::MapConfig;
+
+/// Turns a bomb may tick before it explodes, 0 explodes at the end of the turn it is placed.
+pub const BOMB_TIMER_RANGE: RangeInclusive<usize> = 0..=10;
+/// Cells an explosion may reach in every direction.
+pub const BOMB_RADIUS_RANGE: RangeInclusive<usize> = 1..=20;
+
+/// Checks that bombs explode within a sensible time and reach at least their neighbours. Used with a
+/// `MapValidatorNode`.
+pub fn validate_bomb_settings(config: &MapConfig, _player_count: usize) -> Result<(), MapConfigError> {
+    if !BOMB_TIMER_RANGE.contains(&config.bomb_timer) {
+        return Err(MapConfigError::BombTimerOutOfRange { timer: config.bomb_timer, allowed: BOMB_TIMER_RANGE });
+    }
+    if !BOMB_RADIUS_RANGE.contains(&config.bomb_radius) {
+        return Err(MapConfigError::BombRadiusOutOfRange { radius: config.bomb_radius, allowed: BOMB_RADIUS_RANGE });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bomb_settings_must_be_in_range() {
+        //Arrange
+        let valid = MapConfig::default();
+        let slow_timer = MapConfig { bomb_timer: 11, ..MapConfig::default() };
+        let huge_radius = MapConfig { bomb_radius: 21, ..MapConfig::default() };
+
+        //Act & Assert
+        assert!(validate_bomb_settings(&valid, 2).is_ok());
+        assert_eq!(validate_bomb_settings(&slow_timer, 2), Err(MapConfigError::BombTimerOutOfRange { timer: 11, allowed: BOMB_TIMER_RANGE }));
+        assert!(matches!(validate_bomb_settings(&huge_radius, 2), Err(MapConfigError::BombRadiusOutOfRange { radius: 21, .. })));
+    }
+}
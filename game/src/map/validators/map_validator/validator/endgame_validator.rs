@@ -0,0 +1,33 @@
+use crate::map::enums::map_config_error::MapConfigError;
+use crate::map::structs::map_config::MapConfig;
+
+/// Most turns per map cell before the map has to start shrinking.
+pub const MAX_ENDGAME_TURNS_PER_CELL: usize = 20;
+
+/// Checks that the endgame starts within a reasonable time for the size of the map, so a game
+/// between bots that avoid each other still ends. Used with a `MapValidatorNode`.
+pub fn validate_endgame(config: &MapConfig, _player_count: usize) -> Result<(), MapConfigError> {
+    let max = MAX_ENDGAME_TURNS_PER_CELL * config.width() * config.height();
+    if config.endgame > max {
+        return Err(MapConfigError::EndgameTooLate { endgame: config.endgame, max });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_endgame_is_limited_by_map_area() {
+        //Arrange
+        let small = MapConfig { size: 7, endgame: 49 * MAX_ENDGAME_TURNS_PER_CELL, ..MapConfig::default() };
+        let too_late = MapConfig { endgame: small.endgame + 1, ..small.clone() };
+        let larger = MapConfig { size: 9, ..too_late.clone() };
+
+        //Act & Assert
+        assert!(validate_endgame(&small, 2).is_ok());
+        assert_eq!(validate_endgame(&too_late, 2), Err(MapConfigError::EndgameTooLate { endgame: 981, max: 980 }));
+        assert!(validate_endgame(&larger, 2).is_ok());
+    }
+}
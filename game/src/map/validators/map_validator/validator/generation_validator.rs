@@ -0,0 +1,37 @@
+use crate::map::enums::map_config_error::MapConfigError;
+use crate::map::enums::symmetry::Symmetry;
+use crate::map::structs::map_config::MapConfig;
+
+/// Checks that a generated map can have the symmetry it asks for, quarter turns only fit square
+/// maps. Used with a `MapValidatorNode`.
+pub fn validate_generation(config: &MapConfig, _player_count: usize) -> Result<(), MapConfigError> {
+    let Some(generation) = &config.generation else {
+        return Ok(());
+    };
+    let (width, height) = (config.width(), config.height());
+    if generation.symmetry == Symmetry::Rotational && width != height {
+        return Err(MapConfigError::RotationalSymmetryNotSquare { width, height });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::structs::map_generation_config::MapGenerationConfig;
+
+    #[test]
+    fn test_rotational_symmetry_needs_a_square_map() {
+        //Arrange
+        let rotational = Some(MapGenerationConfig { symmetry: Symmetry::Rotational, ..MapGenerationConfig::default() });
+        let mirror = Some(MapGenerationConfig { symmetry: Symmetry::Mirror, ..MapGenerationConfig::default() });
+        let square = MapConfig { size: 11, generation: rotational.clone(), ..MapConfig::default() };
+        let wide = MapConfig { width: Some(15), height: Some(9), generation: rotational, ..MapConfig::default() };
+        let wide_mirror = MapConfig { width: Some(15), height: Some(9), generation: mirror, ..MapConfig::default() };
+
+        //Act & Assert
+        assert!(validate_generation(&square, 2).is_ok());
+        assert_eq!(validate_generation(&wide, 2), Err(MapConfigError::RotationalSymmetryNotSquare { width: 15, height: 9 }));
+        assert!(validate_generation(&wide_mirror, 2).is_ok());
+    }
+}
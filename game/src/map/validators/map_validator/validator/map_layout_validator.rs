@@ -0,0 +1,125 @@
+use crate::map::enums::map_config_error::MapConfigError;
+use crate::map::grid::cell::CellType;
+use crate::map::structs::map_config::MapConfig;
+use crate::map::structs::map_layout::MapLayout;
+use crate::map::validators::map_validator::traits::map_validator::MapValidator;
+
+/// Checks that a hand-designed layout is consistent with itself and with the map settings: it has
+/// the size of the map, a cell for every position and at least 2 spawns on distinct empty cells.
+/// Layouts are also deserialized from replays and requests, so this does not rely on `MapLayout::parse`.
+pub struct MapLayoutValidator {
+    next: Option<Box<dyn MapValidator>>,
+}
+
+impl Default for MapLayoutValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MapLayoutValidator {
+    pub fn new() -> Self {
+        Self { next: None }
+    }
+
+    fn validate_layout(config: &MapConfig, layout: &MapLayout) -> Result<(), MapConfigError> {
+        if (layout.width, layout.height) != (config.width(), config.height()) {
+            return Err(MapConfigError::LayoutSizeMismatch {
+                layout_width: layout.width,
+                layout_height: layout.height,
+                width: config.width(),
+                height: config.height(),
+            });
+        }
+        let expected = layout.width * layout.height;
+        if layout.tiles.len() != expected {
+            return Err(MapConfigError::LayoutTileCount { tiles: layout.tiles.len(), expected });
+        }
+        if layout.spawns.len() < 2 {
+            return Err(MapConfigError::NotEnoughSpawns { spawns: layout.spawns.len(), players: 2 });
+        }
+        for (index, spawn) in layout.spawns.iter().enumerate() {
+            let number = index + 1;
+            if !spawn.is_valid(layout.width, layout.height) {
+                return Err(MapConfigError::SpawnOutsideMap { spawn: number });
+            }
+            if layout.cell(*spawn) != CellType::Empty {
+                return Err(MapConfigError::SpawnNotEmpty { spawn: number });
+            }
+            if let Some(other) = layout.spawns[..index].iter().position(|other| other == spawn) {
+                return Err(MapConfigError::DuplicateSpawn { spawn: number, other: other + 1 });
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MapValidator for MapLayoutValidator {
+    fn set_next(mut self: Box<Self>, next: Box<dyn MapValidator>) -> Box<dyn MapValidator> {
+        self.next = Some(next);
+        self
+    }
+
+    fn validate(&self, config: &MapConfig, player_count: usize) -> Result<(), MapConfigError> {
+        if let Some(layout) = &config.layout {
+            Self::validate_layout(config, layout)?;
+        }
+        if let Some(ref next_validator) = self.next {
+            next_validator.validate(config, player_count)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::coord::Coord;
+
+    const FAIR: &str = "WWWWWWW\nW1 . 2W\nW W.W W\nW.....W\nW W.W W\nW3 . 4W\nWWWWWWW";
+
+    fn config(map: &str) -> MapConfig {
+        let layout = MapLayout::parse(map).unwrap();
+        MapConfig { width: Some(layout.width), height: Some(layout.height), layout: Some(layout), ..MapConfig::default() }
+    }
+
+    #[test]
+    fn test_parsed_layout_is_valid() {
+        //Act & Assert
+        assert!(MapLayoutValidator::new().validate(&config(FAIR), 4).is_ok());
+    }
+
+    #[test]
+    fn test_layout_size_must_match_map_size() {
+        //Arrange
+        let config = MapConfig { height: Some(9), ..config(FAIR) };
+
+        //Act & Assert
+        assert!(matches!(MapLayoutValidator::new().validate(&config, 2), Err(MapConfigError::LayoutSizeMismatch { .. })));
+    }
+
+    #[test]
+    fn test_inconsistent_layouts_are_invalid() {
+        //Arrange
+        let mut missing_tiles = config(FAIR);
+        missing_tiles.layout.as_mut().unwrap().tiles.pop();
+        let mut spawn_on_wall = config(FAIR);
+        spawn_on_wall.layout.as_mut().unwrap().spawns[1] = Coord::from(0, 0);
+        let mut spawn_outside = config(FAIR);
+        spawn_outside.layout.as_mut().unwrap().spawns[2] = Coord::from(7, 1);
+        let mut shared_spawn = config(FAIR);
+        shared_spawn.layout.as_mut().unwrap().spawns[3] = Coord::from(1, 1);
+        let single_spawn = config("WWWWW\nW1  W\nW   W\nWWWWW");
+
+        //Act
+        let validate = |config: &MapConfig| MapLayoutValidator::new().validate(config, 2);
+
+        //Assert
+        assert_eq!(validate(&missing_tiles), Err(MapConfigError::LayoutTileCount { tiles: 48, expected: 49 }));
+        assert_eq!(validate(&spawn_on_wall), Err(MapConfigError::SpawnNotEmpty { spawn: 2 }));
+        assert_eq!(validate(&spawn_outside), Err(MapConfigError::SpawnOutsideMap { spawn: 3 }));
+        assert_eq!(validate(&shared_spawn), Err(MapConfigError::DuplicateSpawn { spawn: 4, other: 1 }));
+        assert_eq!(validate(&single_spawn), Err(MapConfigError::NotEnoughSpawns { spawns: 1, players: 2 }));
+    }
+}
@@ -1,4 +1,4 @@
-
+use crate::map::enums::map_config_error::MapConfigError;
 use crate::map::structs::map_config::MapConfig;
 use crate::map::validators::map_validator::traits::map_validator::MapValidator;
 
@@ -6,6 +6,12 @@ pub struct MapSizeValidator {
     next: Option<Box<dyn MapValidator>>,
 }
 
+impl Default for MapSizeValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl MapSizeValidator {
     pub fn new() -> Self {
         Self { next: None }
@@ -18,14 +24,14 @@ impl MapValidator for MapSizeValidator {
         self
     }
 
-    fn validate(&self, config: &MapConfig) -> Result<(), String> {
-        let w = config.size;
-        let h = config.size;
+    fn validate(&self, config: &MapConfig, player_count: usize) -> Result<(), MapConfigError> {
+        let w = config.width();
+        let h = config.height();
         if w < 7 || h < 7 || w > 20 || h > 20 || w.is_multiple_of(2) || h.is_multiple_of(2) {
-            return Err(format!("Invalid map size: {w}x{h}. Must be odd and 7-20"));
+            return Err(MapConfigError::InvalidSize { width: w, height: h });
         }
         if let Some(ref next_validator) = self.next {
-            next_validator.validate(config)
+            next_validator.validate(config, player_count)
         } else {
             Ok(())
         }
@@ -41,33 +47,33 @@ mod tests {
     fn test_valid_map_sizes() {
         let validator = MapSizeValidator::new();
         let config = MapConfig { size: 7, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_ok());
+        assert!(validator.validate(&config, 2).is_ok());
 
         let config = MapConfig { size: 19, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_ok());
+        assert!(validator.validate(&config, 2).is_ok());
 
         let config = MapConfig { size: 11, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_ok());
+        assert!(validator.validate(&config, 2).is_ok());
     }
 
     #[test]
     fn test_invalid_map_sizes_too_small() {
         let validator = MapSizeValidator::new();
         let config = MapConfig { size: 5, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_err());
+        assert!(validator.validate(&config, 2).is_err());
 
         let config = MapConfig { size: 4, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_err());
+        assert!(validator.validate(&config, 2).is_err());
     }
 
     #[test]
     fn test_invalid_map_sizes_too_large() {
         let validator = MapSizeValidator::new();
         let config = MapConfig { size: 21, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_err());
+        assert!(validator.validate(&config, 2).is_err());
 
         let config = MapConfig { size: 22, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_err());
+        assert!(validator.validate(&config, 2).is_err());
     }
 
     #[test]
@@ -75,9 +81,19 @@ mod tests {
         let validator = MapSizeValidator::new();
         let config = MapConfig { size: 5, .. MapConfig::default() };
 
-        assert!(validator.validate(&config).is_err());
+        assert!(validator.validate(&config, 2).is_err());
 
         let config = MapConfig { size: 8, .. MapConfig::default() };
-        assert!(validator.validate(&config).is_err());
+        assert!(validator.validate(&config, 2).is_err());
+    }
+
+    #[test]
+    fn test_rectangular_map_sizes() {
+        let validator = MapSizeValidator::new();
+        let config = MapConfig { width: Some(19), height: Some(7), .. MapConfig::default() };
+        assert!(validator.validate(&config, 2).is_ok());
+
+        let config = MapConfig { width: Some(19), height: Some(8), .. MapConfig::default() };
+        assert!(validator.validate(&config, 2).is_err());
     }
 }
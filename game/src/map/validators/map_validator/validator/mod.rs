@@ -1 +1,7 @@
-pub mod map_size_validator;
\ No newline at end of file
+pub mod bomb_settings_validator;
+pub mod endgame_validator;
+pub mod generation_validator;
+pub mod map_layout_validator;
+pub mod map_size_validator;
+pub mod spawn_count_validator;
+pub mod spawn_reachability_validator;
@@ -0,0 +1,26 @@
+use crate::map::enums::map_config_error::MapConfigError;
+use crate::map::structs::map_config::MapConfig;
+
+/// Checks that every player has a spawn of its own. Used with a `MapValidatorNode`.
+pub fn validate_spawn_count(config: &MapConfig, player_count: usize) -> Result<(), MapConfigError> {
+    let spawns = config.spawn_positions().len();
+    if spawns < player_count {
+        return Err(MapConfigError::NotEnoughSpawns { spawns, players: player_count });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spawn_count_limits_player_count() {
+        //Arrange
+        let config = MapConfig::default();
+
+        //Act & Assert
+        assert!(validate_spawn_count(&config, 4).is_ok());
+        assert_eq!(validate_spawn_count(&config, 5), Err(MapConfigError::NotEnoughSpawns { spawns: 4, players: 5 }));
+    }
+}
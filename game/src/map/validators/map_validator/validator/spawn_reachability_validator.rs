@@ -0,0 +1,146 @@
+use std::collections::VecDeque;
+use crate::coord::Coord;
+use crate::map::enums::map_config_error::MapConfigError;
+use crate::map::grid::cell::CellType;
+use crate::map::structs::map_config::MapConfig;
+use crate::map::structs::map_layout::MapLayout;
+use crate::map::validators::map_validator::traits::map_validator::MapValidator;
+
+/// Largest allowed difference between the spawns in distance to their nearest opponent.
+pub const MAX_SPAWN_DISTANCE_DIFFERENCE: usize = 2;
+
+/// Checks the spawns of a hand-designed layout: all spawns must be able to reach each other, and no
+/// spawn may have a head start in room to move or distance to the nearest opponent.
+///
+/// Generated maps are checked with `check_spawns` while they are generated, and the default map
+/// always connects its corners.
+pub struct SpawnReachabilityValidator {
+    next: Option<Box<dyn MapValidator>>,
+}
+
+impl Default for SpawnReachabilityValidator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpawnReachabilityValidator {
+    pub fn new() -> Self {
+        Self { next: None }
+    }
+
+    /// Checks that all spawns can reach each other and are equally far from their nearest opponent.
+    pub(crate) fn check_spawns(layout: &MapLayout) -> Result<(), MapConfigError> {
+        let mut nearest_opponent = Vec::new();
+        for (index, spawn) in layout.spawns.iter().enumerate() {
+            let number = index + 1;
+            if !neighbours(*spawn).any(|c| layout.cell(c).is_walkable()) {
+                return Err(MapConfigError::SpawnBlocked { spawn: number });
+            }
+            let distances = Self::distances_from(layout, *spawn);
+            let mut nearest = usize::MAX;
+            for (other, opponent) in layout.spawns.iter().enumerate().filter(|(other, _)| *other != index) {
+                match distances[layout_index(layout, *opponent)] {
+                    Some(distance) => nearest = nearest.min(distance),
+                    None => return Err(MapConfigError::UnreachableSpawn { spawn: other + 1, from: number }),
+                }
+            }
+            nearest_opponent.push(nearest);
+        }
+
+        let closest = nearest_opponent.iter().min().copied().unwrap_or_default();
+        let furthest = nearest_opponent.iter().max().copied().unwrap_or_default();
+        if furthest - closest > MAX_SPAWN_DISTANCE_DIFFERENCE {
+            return Err(MapConfigError::UnfairSpawns { closest, furthest, max_difference: MAX_SPAWN_DISTANCE_DIFFERENCE });
+        }
+        Ok(())
+    }
+
+    /// Steps from `start` to every cell, where destroyable cells count as passable because they can be
+    /// blown up. `None` for cells that cannot be reached.
+    fn distances_from(layout: &MapLayout, start: Coord) -> Vec<Option<usize>> {
+        let mut distances = vec![None; layout.tiles.len()];
+        distances[layout_index(layout, start)] = Some(0);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((coord, distance)) = queue.pop_front() {
+            for next in neighbours(coord) {
+                if layout.cell(next) == CellType::Wall || distances[layout_index(layout, next)].is_some() {
+                    continue;
+                }
+                distances[layout_index(layout, next)] = Some(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+        distances
+    }
+}
+
+fn layout_index(layout: &MapLayout, coord: Coord) -> usize {
+    coord.row.get() * layout.width + coord.col.get()
+}
+
+fn neighbours(coord: Coord) -> impl Iterator<Item = Coord> {
+    [coord.move_up(), coord.move_down(), coord.move_left(), coord.move_right()].into_iter().flatten()
+}
+
+impl MapValidator for SpawnReachabilityValidator {
+    fn set_next(mut self: Box<Self>, next: Box<dyn MapValidator>) -> Box<dyn MapValidator> {
+        self.next = Some(next);
+        self
+    }
+
+    fn validate(&self, config: &MapConfig, player_count: usize) -> Result<(), MapConfigError> {
+        if let Some(layout) = &config.layout {
+            Self::check_spawns(layout)?;
+        }
+        if let Some(ref next_validator) = self.next {
+            next_validator.validate(config, player_count)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(map: &str) -> MapConfig {
+        let layout = MapLayout::parse(map).unwrap();
+        MapConfig { width: Some(layout.width), height: Some(layout.height), layout: Some(layout), ..MapConfig::default() }
+    }
+
+    #[test]
+    fn test_fair_layout_is_valid() {
+        //Arrange
+        let config = config("WWWWWWW\nW1 . 2W\nW W.W W\nW.....W\nW W.W W\nW3 . 4W\nWWWWWWW");
+
+        //Act & Assert
+        assert!(SpawnReachabilityValidator::new().validate(&config, 4).is_ok());
+    }
+
+    #[test]
+    fn test_walled_off_spawn_is_invalid() {
+        //Arrange
+        let config = config("WWWWWWW\nW1 W  W\nW  W  W\nWWWW  W\nW    2W\nW     W\nWWWWWWW");
+
+        //Act
+        let result = SpawnReachabilityValidator::new().validate(&config, 2);
+
+        //Assert
+        assert_eq!(result, Err(MapConfigError::UnreachableSpawn { spawn: 2, from: 1 }));
+        assert_eq!(result.unwrap_err().to_string(), "Spawn 2 cannot be reached from spawn 1");
+    }
+
+    #[test]
+    fn test_unfair_spawns_are_invalid() {
+        //Arrange
+        let config = config("WWWWWWW\nW12   W\nW     W\nW     W\nW     W\nW    3W\nWWWWWWW");
+
+        //Act
+        let result = SpawnReachabilityValidator::new().validate(&config, 3);
+
+        //Assert
+        assert!(result.unwrap_err().to_string().starts_with("Spawns are unfair"));
+    }
+}
@@ -2,8 +2,9 @@ use criterion::{criterion_group, criterion_main, Criterion};
 use bots::available_bots;
 use tournament::tournament;
 use crate::tournament::prepare_bots;
+use rand::{rng, Rng};
 
-fn tournament_benchmark(c: &mut Criterion) {
+fn tournament_benchmark(_c: &mut Criterion) {
     let mut config = Criterion::default()
         .sample_size(10000) // default is 100; increase for more precision
         .measurement_time(std::time::Duration::from_secs(120)) // default is 5s
@@ -13,10 +14,11 @@ fn tournament_benchmark(c: &mut Criterion) {
     config.bench_function("run_tournament", |b| {
         b.iter(|| {
             let bots = available_bots();
-            let game_bots = prepare_bots(&bots, 2);
+            let mut rng = rng();
+            let game_bots = prepare_bots(&bots, 2, &mut rng);
 
 
-            tournament::run_game(game_bots, 11);
+            tournament::run_game(game_bots, 11, 11, rng.random()).expect("valid map settings");
         });
     });
 
@@ -1,8 +1,46 @@
+use std::time::Duration;
+use game::map::enums::map_config_error::MapConfigError;
+use game::map::enums::shrink_pattern::ShrinkPattern;
+use game::map::enums::symmetry::Symmetry;
+use game::map::structs::map_config::MapConfig;
+use game::map::structs::map_generation_config::MapGenerationConfig;
+use game::map::structs::map_layout::MapLayout;
+use game::map::structs::time_budget::{TimeBudget, TimeoutPolicy};
+use game::map::validators::map_validator::map_validator_chain_factory::MapValidatorChainFactory;
+use crate::tournament::tournament_settings;
+
 /// Represents a single game configuration
 #[derive(Debug, Clone)]
 pub struct GameConfig {
     pub num_players: usize,
-    pub size: usize,
+    pub width: usize,
+    pub height: usize,
+    pub seed: u64,
+    pub time_budget: TimeBudget,
+    /// Hand-designed arena, `width` and `height` then equal the layout size.
+    pub layout: Option<MapLayout>,
+    /// Random map settings, so bots cannot overfit one fixed grid.
+    pub generation: Option<MapGenerationConfig>,
+    /// How the map closes in once the endgame starts.
+    pub shrink: ShrinkPattern,
+}
+
+impl GameConfig {
+    /// The map settings the games of this config are played with.
+    pub fn map_config(&self) -> MapConfig {
+        MapConfig {
+            time_budget: self.time_budget.clone(),
+            layout: self.layout.clone(),
+            generation: self.generation.clone(),
+            shrink: self.shrink,
+            ..tournament_settings(self.width, self.height, self.seed)
+        }
+    }
+
+    /// Checks that the map of this config can be played by `num_players` players.
+    pub fn validate(&self) -> Result<(), MapConfigError> {
+        MapValidatorChainFactory::validate(&self.map_config(), self.num_players)
+    }
 }
 
 /// Utility to generate odd numbers in a range
@@ -16,18 +54,82 @@ fn odd_numbers_in_range(start: usize, end: usize) -> Vec<usize> {
 pub struct ConfigFactory;
 
 impl ConfigFactory {
-    /// Generates all tournament configs with given player counts and map sizes
+    /// Time budget for tournament games, so one slow bot cannot stall a tournament thread. A bot
+    /// that never returns is not covered, see `TimeBudget`.
+    pub fn tournament_time_budget() -> TimeBudget {
+        TimeBudget {
+            per_move: Some(Duration::from_millis(100)),
+            per_game: Some(Duration::from_secs(5)),
+            on_timeout: TimeoutPolicy::Wait,
+        }
+    }
+
+    /// Plays every config with `time_budget` instead of the tournament budget
+    pub fn with_time_budget(configs: Vec<GameConfig>, time_budget: &TimeBudget) -> Vec<GameConfig> {
+        configs
+            .into_iter()
+            .map(|config| GameConfig { time_budget: time_budget.clone(), ..config })
+            .collect()
+    }
+
+    /// Square maps of every size, and wide maps that are 6 columns wider than they are high
+    pub fn tournament_dimensions() -> Vec<(usize, usize)> {
+        let map_sizes = odd_numbers_in_range(7, 20);
+        let square = map_sizes.iter().map(|&size| (size, size));
+        let wide = map_sizes.iter().filter(|&&size| size + 6 < 20).map(|&size| (size + 6, size));
+        square.chain(wide).collect()
+    }
+
+    /// Generates all tournament configs with given player counts, map dimensions, symmetries and
+    /// shrink patterns. Every game generates its own map from its seed.
     pub fn generate_tournament_configs() -> Vec<GameConfig> {
         let player_counts = [2, 3, 4];
-        let map_sizes = odd_numbers_in_range(7, 20);
+        let symmetries = [Symmetry::Mirror, Symmetry::Rotational];
+        let shrink_patterns = [ShrinkPattern::Spiral, ShrinkPattern::Rings { interval: 10 }, ShrinkPattern::Random];
 
         let mut configs = Vec::new();
 
-        for size in map_sizes {
+        for (width, height) in Self::tournament_dimensions() {
             for &players in &player_counts {
+                // Quarter turns only fit square maps
+                for symmetry in symmetries.into_iter().filter(|symmetry| width == height || *symmetry != Symmetry::Rotational) {
+                    for shrink in shrink_patterns {
+                        configs.push(GameConfig {
+                            num_players: players,
+                            width,
+                            height,
+                            seed: 0,
+                            time_budget: Self::tournament_time_budget(),
+                            layout: None,
+                            generation: Some(MapGenerationConfig { symmetry, ..MapGenerationConfig::default() }),
+                            shrink,
+                        });
+                    }
+                }
+            }
+        }
+
+        configs
+    }
+
+    /// Generates tournament configs that play on the given layouts, with every player count the
+    /// layout has spawns for
+    pub fn generate_layout_configs(layouts: &[MapLayout]) -> Vec<GameConfig> {
+        let player_counts = [2, 3, 4];
+
+        let mut configs = Vec::new();
+
+        for layout in layouts {
+            for &players in player_counts.iter().filter(|&&players| players <= layout.spawns.len()) {
                 configs.push(GameConfig {
                     num_players: players,
-                    size,
+                    width: layout.width,
+                    height: layout.height,
+                    seed: 0,
+                    time_budget: Self::tournament_time_budget(),
+                    layout: Some(layout.clone()),
+                    generation: None,
+                    shrink: ShrinkPattern::default(),
                 });
             }
         }
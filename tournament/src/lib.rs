@@ -1,3 +1,6 @@
 pub mod tournament;
 pub mod tournament_result;
-pub mod factories;
\ No newline at end of file
+pub mod factories;
+pub mod rating;
+pub mod replay_selection;
+pub mod merge_error;
\ No newline at end of file
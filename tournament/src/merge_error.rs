@@ -0,0 +1,19 @@
+use std::fmt;
+use crate::rating::rating::RatingSystemKind;
+
+/// Why two tournament results cannot be merged.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeError {
+    /// The results rate bots with different rating systems, whose ratings cannot be combined.
+    DifferentRatingSystems { ours: RatingSystemKind, theirs: RatingSystemKind },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MergeError::DifferentRatingSystems { ours, theirs } => write!(f, "Cannot merge {theirs:?} ratings into {ours:?} ratings"),
+        }
+    }
+}
+
+impl std::error::Error for MergeError {}
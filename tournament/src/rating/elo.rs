@@ -0,0 +1,102 @@
+use super::rating::{Rating, RatingSystem};
+
+/// Elo for multiplayer games: a game is split into a match between every pair of bots,
+/// and the rating change is averaged over the opponents.
+///
+/// Elo keeps no uncertainty, so its ratings have an `uncertainty` of 0 and rank by their mean.
+/// Use TrueSkill to keep bots with only a few lucky games from the top of the standings.
+pub struct Elo {
+    pub initial_rating: f64,
+    pub k_factor: f64,
+}
+
+impl Default for Elo {
+    fn default() -> Self {
+        Self {
+            initial_rating: 1500.0,
+            k_factor: 32.0,
+        }
+    }
+}
+
+impl Elo {
+    fn expected_score(rating: f64, opponent: f64) -> f64 {
+        1.0 / (1.0 + 10f64.powf((opponent - rating) / 400.0))
+    }
+}
+
+impl RatingSystem for Elo {
+    fn initial_rating(&self) -> Rating {
+        Rating {
+            mean: self.initial_rating,
+            uncertainty: 0.0,
+            games: 0,
+        }
+    }
+
+    fn rate(&self, ratings: &[Rating], places: &[usize]) -> Vec<Rating> {
+        let opponents = ratings.len().saturating_sub(1).max(1) as f64;
+
+        ratings
+            .iter()
+            .enumerate()
+            .map(|(i, rating)| {
+                let delta: f64 = ratings
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i)
+                    .map(|(j, opponent)| {
+                        let actual = match places[i].cmp(&places[j]) {
+                            std::cmp::Ordering::Less => 1.0,
+                            std::cmp::Ordering::Equal => 0.5,
+                            std::cmp::Ordering::Greater => 0.0,
+                        };
+                        actual - Self::expected_score(rating.mean, opponent.mean)
+                    })
+                    .sum();
+
+                Rating {
+                    mean: rating.mean + self.k_factor * delta / opponents,
+                    uncertainty: 0.0,
+                    games: rating.games + 1,
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winner_gains_and_loser_loses() {
+        //Arrange
+        let elo = Elo::default();
+        let ratings = vec![elo.initial_rating(), elo.initial_rating()];
+
+        //Act
+        let new_ratings = elo.rate(&ratings, &[0, 1]);
+
+        //Assert
+        assert_eq!(new_ratings[0].mean, 1516.0);
+        assert_eq!(new_ratings[1].mean, 1484.0);
+        assert_eq!(new_ratings[0].games, 1);
+        assert_eq!(new_ratings[0].conservative(), new_ratings[0].mean);
+    }
+
+    #[test]
+    fn test_tied_bots_get_the_same_rating() {
+        //Arrange
+        let elo = Elo::default();
+        let ratings = vec![elo.initial_rating(); 3];
+
+        //Act
+        let new_ratings = elo.rate(&ratings, &[0, 1, 1]);
+
+        //Assert
+        assert!(new_ratings[0].mean > 1500.0);
+        assert_eq!(new_ratings[1].mean, new_ratings[2].mean);
+        assert!(new_ratings[1].mean < 1500.0);
+    }
+}
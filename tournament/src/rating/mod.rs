@@ -0,0 +1,3 @@
+pub mod rating;
+pub mod elo;
+pub mod trueskill;
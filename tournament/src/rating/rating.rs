@@ -0,0 +1,55 @@
+use std::fmt;
+use serde::{Deserialize, Serialize};
+use super::elo::Elo;
+use super::trueskill::TrueSkill;
+
+/// A skill estimate of a bot together with how certain we are about it.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rating {
+    pub mean: f64,
+    /// Standard deviation of the estimate, 0 for rating systems that do not model it.
+    pub uncertainty: f64,
+    pub games: usize,
+}
+
+impl Rating {
+    /// Pessimistic estimate used for ranking, so a bot with a handful of lucky games does not top the list.
+    pub fn conservative(&self) -> f64 {
+        self.mean - 3.0 * self.uncertainty
+    }
+}
+
+impl fmt::Display for Rating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.uncertainty == 0.0 {
+            return write!(f, "{:.1}", self.mean);
+        }
+        write!(f, "{:.1} ± {:.1}", self.mean, self.uncertainty)
+    }
+}
+
+/// A rating model that updates bot ratings from the finishing order of a game.
+pub trait RatingSystem {
+    fn initial_rating(&self) -> Rating;
+
+    /// Returns the new ratings after one game. `places[i]` is the finishing place of the bot with
+    /// `ratings[i]`, 0 being the winner. Bots with the same place tied.
+    fn rate(&self, ratings: &[Rating], places: &[usize]) -> Vec<Rating>;
+}
+
+/// Selects the rating system used by a tournament.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RatingSystemKind {
+    Elo,
+    #[default]
+    TrueSkill,
+}
+
+impl RatingSystemKind {
+    pub fn system(&self) -> Box<dyn RatingSystem> {
+        match self {
+            RatingSystemKind::Elo => Box::new(Elo::default()),
+            RatingSystemKind::TrueSkill => Box::new(TrueSkill::default()),
+        }
+    }
+}
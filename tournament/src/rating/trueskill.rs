@@ -0,0 +1,216 @@
+use std::f64::consts::{PI, SQRT_2};
+use super::rating::{Rating, RatingSystem};
+
+/// Smallest factor a variance can shrink to in a single game.
+const MIN_VARIANCE_FACTOR: f64 = 0.0001;
+
+/// TrueSkill-style Gaussian rating. Every bot has a skill `mean` with a standard deviation
+/// `uncertainty`. A multiplayer game is decomposed into pairwise comparisons between all bots,
+/// and the updates are averaged over the opponents, so a four player game moves a rating about as
+/// far as a two player game instead of three times as far.
+pub struct TrueSkill {
+    pub mu: f64,
+    pub sigma: f64,
+    /// Performance noise of a single game.
+    pub beta: f64,
+    /// Skill drift added before every game, so ratings keep moving.
+    pub tau: f64,
+    pub draw_probability: f64,
+}
+
+impl Default for TrueSkill {
+    fn default() -> Self {
+        let sigma = 25.0 / 3.0;
+        Self {
+            mu: 25.0,
+            sigma,
+            beta: sigma / 2.0,
+            tau: sigma / 100.0,
+            draw_probability: 0.1,
+        }
+    }
+}
+
+impl TrueSkill {
+    fn draw_margin(&self) -> f64 {
+        inverse_cdf((self.draw_probability + 1.0) / 2.0) * SQRT_2 * self.beta
+    }
+}
+
+impl RatingSystem for TrueSkill {
+    fn initial_rating(&self) -> Rating {
+        Rating {
+            mean: self.mu,
+            uncertainty: self.sigma,
+            games: 0,
+        }
+    }
+
+    fn rate(&self, ratings: &[Rating], places: &[usize]) -> Vec<Rating> {
+        let margin = self.draw_margin();
+        let variances: Vec<f64> = ratings
+            .iter()
+            .map(|rating| rating.uncertainty.powi(2) + self.tau.powi(2))
+            .collect();
+
+        ratings
+            .iter()
+            .enumerate()
+            .map(|(i, rating)| {
+                let mut mean_delta = 0.0;
+                let mut variance_factor = 1.0;
+
+                for (j, opponent) in ratings.iter().enumerate().filter(|(j, _)| *j != i) {
+                    let c = (2.0 * self.beta.powi(2) + variances[i] + variances[j]).sqrt();
+                    let epsilon = margin / c;
+                    let (sign, v, w) = match places[i].cmp(&places[j]) {
+                        std::cmp::Ordering::Less => {
+                            let t = (rating.mean - opponent.mean) / c;
+                            (1.0, v_win(t, epsilon), w_win(t, epsilon))
+                        }
+                        std::cmp::Ordering::Greater => {
+                            let t = (opponent.mean - rating.mean) / c;
+                            (-1.0, v_win(t, epsilon), w_win(t, epsilon))
+                        }
+                        std::cmp::Ordering::Equal => {
+                            let t = (rating.mean - opponent.mean) / c;
+                            (1.0, v_draw(t, epsilon), w_draw(t, epsilon))
+                        }
+                    };
+                    mean_delta += sign * variances[i] / c * v;
+                    variance_factor -= variances[i] / c.powi(2) * w;
+                }
+
+                let opponents = (ratings.len() - 1).max(1) as f64;
+                let variance_factor = 1.0 - (1.0 - variance_factor) / opponents;
+                let variance = variances[i] * variance_factor.max(MIN_VARIANCE_FACTOR);
+                Rating {
+                    mean: rating.mean + mean_delta / opponents,
+                    uncertainty: variance.sqrt(),
+                    games: rating.games + 1,
+                }
+            })
+            .collect()
+    }
+}
+
+fn pdf(x: f64) -> f64 {
+    (-x * x / 2.0).exp() / (2.0 * PI).sqrt()
+}
+
+fn cdf(x: f64) -> f64 {
+    0.5 * erfc(-x / SQRT_2)
+}
+
+/// Complementary error function, Chebyshev approximation with a relative error below 1.2e-7.
+fn erfc(x: f64) -> f64 {
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let r = t * (-z * z - 1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277)))))))))
+        .exp();
+    if x >= 0.0 { r } else { 2.0 - r }
+}
+
+/// Inverse of `cdf`, found by bisection.
+fn inverse_cdf(p: f64) -> f64 {
+    let (mut low, mut high) = (-10.0, 10.0);
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if cdf(mid) < p {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+    (low + high) / 2.0
+}
+
+fn v_win(t: f64, epsilon: f64) -> f64 {
+    let denominator = cdf(t - epsilon);
+    if denominator < f64::MIN_POSITIVE {
+        return epsilon - t;
+    }
+    pdf(t - epsilon) / denominator
+}
+
+fn w_win(t: f64, epsilon: f64) -> f64 {
+    let v = v_win(t, epsilon);
+    (v * (v + t - epsilon)).clamp(0.0, 1.0)
+}
+
+fn v_draw(t: f64, epsilon: f64) -> f64 {
+    let denominator = cdf(epsilon - t) - cdf(-epsilon - t);
+    if denominator < f64::MIN_POSITIVE {
+        return if t < 0.0 { -t - epsilon } else { -t + epsilon };
+    }
+    (pdf(-epsilon - t) - pdf(epsilon - t)) / denominator
+}
+
+fn w_draw(t: f64, epsilon: f64) -> f64 {
+    let denominator = cdf(epsilon - t) - cdf(-epsilon - t);
+    if denominator < f64::MIN_POSITIVE {
+        return 1.0;
+    }
+    let v = v_draw(t, epsilon);
+    (v * v + ((epsilon - t) * pdf(epsilon - t) + (epsilon + t) * pdf(epsilon + t)) / denominator).clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_winner_gains_and_uncertainty_shrinks() {
+        //Arrange
+        let trueskill = TrueSkill::default();
+        let ratings = vec![trueskill.initial_rating(); 3];
+
+        //Act
+        let new_ratings = trueskill.rate(&ratings, &[0, 1, 2]);
+
+        //Assert
+        assert!(new_ratings[0].mean > new_ratings[1].mean);
+        assert!(new_ratings[1].mean > new_ratings[2].mean);
+        assert!(new_ratings.iter().all(|rating| rating.uncertainty < trueskill.sigma));
+    }
+
+    #[test]
+    fn test_multiplayer_game_keeps_mean_rating() {
+        //Arrange
+        let trueskill = TrueSkill::default();
+        let ratings: Vec<Rating> = [22.0, 25.0, 27.0, 30.0]
+            .iter()
+            .map(|&mean| Rating { mean, ..trueskill.initial_rating() })
+            .collect();
+        let mean_before = ratings.iter().map(|rating| rating.mean).sum::<f64>() / 4.0;
+
+        //Act
+        let new_ratings = trueskill.rate(&ratings, &[0, 1, 1, 3]);
+
+        //Assert
+        let mean_after = new_ratings.iter().map(|rating| rating.mean).sum::<f64>() / 4.0;
+        assert!((mean_after - mean_before).abs() < 1e-9);
+        assert!(new_ratings[0].mean > ratings[0].mean);
+        assert!(new_ratings[3].mean < ratings[3].mean);
+    }
+
+    #[test]
+    fn test_multiplayer_win_moves_rating_like_a_duel() {
+        //Arrange
+        let trueskill = TrueSkill::default();
+
+        //Act
+        let duel = trueskill.rate(&[trueskill.initial_rating(); 2], &[0, 1]);
+        let four_players = trueskill.rate(&[trueskill.initial_rating(); 4], &[0, 1, 1, 1]);
+
+        //Assert
+        assert!((four_players[0].mean - duel[0].mean).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,16 @@
+/// Which games of a tournament to keep for saving as replays.
+#[derive(Debug, Clone, Default)]
+pub struct ReplaySelection {
+    /// Bots whose lost games are kept.
+    pub losses_of: Vec<String>,
+    /// Maximum number of lost games to keep, the first ones lost are kept.
+    pub max_losses: usize,
+    /// Number of highest scoring games to keep.
+    pub top: usize,
+}
+
+impl ReplaySelection {
+    pub fn is_empty(&self) -> bool {
+        self.losses_of.is_empty() && self.top == 0
+    }
+}
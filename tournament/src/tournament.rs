@@ -2,39 +2,61 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::time::{Duration, Instant};
 use game::map::structs::map_config::MapConfig;
+use game::map::structs::map_layout::MapLayout;
+use rand::rngs::StdRng;
 use rand::seq::index::sample;
-use rand::{rng};
+use rand::{rng, Rng, SeedableRng};
 use game::bot::bot::{BotConstructor, BotController};
 use game::game::game::Game;
+use game::game::game_error::GameError;
 use game::game::game_result::GameResult;
+use game::map::enums::death_cause::DeathCause;
+use game::map::enums::game_outcome::GameOutcome;
+use game::map::enums::move_resolution::MoveResolution;
 use game::game::replay_engine::{GameReplaySnapshot, ReplayEngine};
 use crate::factories::game_config_factory::{GameConfig};
 use crate::tournament_result::{Score, TournamentResult};
 
 
 /// Runs a tournament for a given duration with the specified number of players per game.
+/// Every game gets a fresh seed, which is stored in its `GameResult`. A game the engine cannot play
+/// is recorded in `failed_games` and the tournament goes on.
 pub fn run_tournament(bot_constructors: &[BotConstructor], round_counter: Option<Arc<AtomicUsize>>, duration: Duration, game_config: Vec<GameConfig>) -> TournamentResult {
-    let mut tournament_result = TournamentResult::new();
+    run_tournament_into(TournamentResult::new(), bot_constructors, round_counter, duration, game_config)
+}
+
+/// Runs a tournament like `run_tournament`, adding the games to `tournament_result`. Its rating
+/// system and replay selection are used.
+pub fn run_tournament_into(mut tournament_result: TournamentResult, bot_constructors: &[BotConstructor], round_counter: Option<Arc<AtomicUsize>>, duration: Duration, game_config: Vec<GameConfig>) -> TournamentResult {
     let start = Instant::now();
     let mut config_iter = game_config.iter().cycle();
+    let mut rng = rng();
 
     while start.elapsed() < duration {
-        let config = config_iter.next().unwrap();
-        run_tournament_game(&mut tournament_result, bot_constructors, &round_counter, config);
+        let config = GameConfig { seed: rng.random(), ..config_iter.next().unwrap().clone() };
+        if let Err(err) = run_tournament_game(&mut tournament_result, bot_constructors, &round_counter, &config) {
+            tournament_result.add_failed_game(&config, err);
+        }
     }
 
     tournament_result
 }
 
 
-pub fn run_tournament_game(tournament_result: &mut TournamentResult, bot_constructors: &[BotConstructor], round_counter: &Option<Arc<AtomicUsize>>, config: &GameConfig) {
-    let game_bots = prepare_bots(bot_constructors, config.num_players);
+/// Runs a single tournament game. The bot selection and the game itself are derived from `config.seed`,
+/// so the same seed, bot list and config produce the same game as long as no bot goes over its time
+/// budget. The `replay_data` of the result always replays it.
+pub fn run_tournament_game(tournament_result: &mut TournamentResult, bot_constructors: &[BotConstructor], round_counter: &Option<Arc<AtomicUsize>>, config: &GameConfig) -> Result<(), GameError> {
+    let mut rng = StdRng::seed_from_u64(config.seed);
+    let game_bots = prepare_bots(bot_constructors, config.num_players, &mut rng);
 
     // Collect names as Strings (we own them)
     let names: Vec<String> = game_bots.iter().map(|b| b.get_name().split(" ").next().unwrap().to_string()).collect();
 
-    let game_result = run_game(game_bots, config.size);
-    let scores_vec = update_scores(&game_result, &names);
+    let game_result = Game::build(game_bots, config.map_config(), None)?.run()?;
+    let scores_vec = update_scores(&game_result);
+    let places = finishing_places(&game_result);
+    tournament_result.keep_replay(&game_result, &names, &scores_vec);
 
     if tournament_result.most_interesting.is_none() || game_result.score > tournament_result.most_interesting.as_ref().unwrap().score {
         tournament_result.most_interesting = Some(game_result);
@@ -43,51 +65,79 @@ pub fn run_tournament_game(tournament_result: &mut TournamentResult, bot_constru
     for (name, score) in names.iter().zip(scores_vec.iter()) {
         tournament_result.add_score(name, *score);
     }
+    tournament_result.add_ratings(&names, &places);
 
     if let Some(counter) = &round_counter {
         counter.fetch_add(1, Ordering::Relaxed);
     }
 
     tournament_result.total_games += 1;
+    Ok(())
 }
 
-pub fn prepare_bots(bot_constructors: &[BotConstructor], player_count: usize) -> Vec<BotController> {
-    let mut rng = rng();
-    let indices = sample(&mut rng, bot_constructors.len(), player_count);
+pub fn prepare_bots(bot_constructors: &[BotConstructor], player_count: usize, rng: &mut impl Rng) -> Vec<BotController> {
+    let indices = sample(rng, bot_constructors.len(), player_count);
     indices.iter().map(|i| bot_constructors[i]()).collect()
 }
 
-/// Runs a single game with the given bots
-pub fn run_game(bots: Vec<BotController>, size: usize) -> GameResult {
-    let settings = MapConfig {
-            bomb_timer: 4,
-            bomb_radius: 3,
-            endgame: 500,
-            size: size
-        };
-    Game::build( bots, settings, None).run()
+/// Map settings used for tournament games
+pub fn tournament_settings(width: usize, height: usize, seed: u64) -> MapConfig {
+    MapConfig {
+        bomb_timer: 4,
+        bomb_radius: 3,
+        endgame: 500,
+        // Moving in player id order would favour the bots that happen to get a low id
+        move_resolution: MoveResolution::SimultaneousBounce,
+        width: Some(width),
+        height: Some(height),
+        seed,
+        ..Default::default()
+    }
+}
+
+/// Runs a single game with the given bots, or tells why it cannot be played
+pub fn run_game(bots: Vec<BotController>, width: usize, height: usize, seed: u64) -> Result<GameResult, GameError> {
+    Game::build(bots, tournament_settings(width, height, seed), None)?.run()
+}
+
+/// Runs a single game with the given bots on a hand-designed map, or tells why it cannot be played
+pub fn run_game_on_layout(bots: Vec<BotController>, layout: MapLayout, seed: u64) -> Result<GameResult, GameError> {
+    let settings = MapConfig { layout: Some(layout.clone()), ..tournament_settings(layout.width, layout.height, seed) };
+    Game::build(bots, settings, None)?.run()
 }
 
 /// Generates a replay snapshot from a game result
-pub fn replay(game_result: &GameResult) -> GameReplaySnapshot {
-    let mut game = Game::build( Vec::new(), game_result.game_settings.clone(), Some(game_result.bots.clone()));
+pub fn replay(game_result: &GameResult) -> Result<GameReplaySnapshot, GameError> {
+    let mut game = Game::build( Vec::new(), game_result.game_settings.clone(), Some(game_result.bots.clone()))?;
     let mut replay_engine = ReplayEngine::new(&mut game);
     replay_engine.to_snapshot(&game_result.replay_data)
 }
 
-/// Updates scores based on the game result
-pub fn update_scores(game_result: &GameResult, bot_names: &[String]) -> Vec<Score> {
-    // Borrow the winner directly if it's a String
-    let winner_name: String = game_result.winner.split(" ").next().unwrap().to_string();
+/// Returns the finishing place of every bot in the game, 0 being the winner.
+/// Bots that died on the same turn share a place.
+pub fn finishing_places(game_result: &GameResult) -> Vec<usize> {
+    game_result.players.iter().map(|player| player.placement - 1).collect()
+}
+
+/// Updates scores based on the game result, in player id order.
+/// In a draw the players that died last each get a draw, everybody else a loss.
+pub fn update_scores(game_result: &GameResult) -> Vec<Score> {
+    let is_draw = game_result.outcome == GameOutcome::Draw;
 
-    bot_names
+    game_result
+        .players
         .iter()
-        .map(|name| {
-            let is_winner = winner_name == name.as_str();
+        .map(|player| {
+            let finished_first = player.placement == 1;
             Score {
-                wins: if is_winner { 1 } else { 0 },
-                losses: if is_winner { 0 } else { 1 },
+                wins: if finished_first && !is_draw { 1 } else { 0 },
+                losses: if finished_first { 0 } else { 1 },
+                draws: if finished_first && is_draw { 1 } else { 0 },
                 total_games: 1,
+                timeouts: player.timeouts,
+                panics: if player.panic.is_some() { 1 } else { 0 },
+                kills: game_result.kills.iter().filter(|kill| kill.credited.contains(&player.id)).count(),
+                suicides: if player.death_cause == Some(DeathCause::Suicide) { 1 } else { 0 },
             }
         })
         .collect()
@@ -1,12 +1,45 @@
 use std::collections::HashMap;
+use game::game::game_error::GameError;
 use game::game::game_result::GameResult;
 use serde::{Deserialize, Serialize};
+use crate::factories::game_config_factory::GameConfig;
+use crate::merge_error::MergeError;
+use crate::rating::rating::{Rating, RatingSystemKind};
+use crate::replay_selection::ReplaySelection;
+
+/// Most failed games kept with their error, later failures are only counted.
+pub const MAX_KEPT_FAILURES: usize = 100;
+
+/// A game the engine could not play, with the config and seed to play it again.
+#[derive(Debug, Clone)]
+pub struct FailedGame {
+    pub config: GameConfig,
+    pub error: GameError,
+}
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct Score {
     pub wins: usize,
     pub losses: usize,
+    #[serde(default)]
+    pub draws: usize,
     pub total_games: usize,
+    #[serde(default)]
+    pub timeouts: usize,
+    #[serde(default)]
+    pub panics: usize,
+    /// Opponents this bot was credited with killing.
+    #[serde(default)]
+    pub kills: usize,
+    #[serde(default)]
+    pub suicides: usize,
+}
+
+/// The finishing order of one rated game. `places[i]` is the finishing place of `botnames[i]`.
+#[derive(Debug, Clone)]
+pub struct RatedGame {
+    pub botnames: Vec<String>,
+    pub places: Vec<usize>,
 }
 
 #[derive(Clone, Serialize, Deserialize)]
@@ -14,29 +47,143 @@ pub struct TournamentResult {
     pub most_interesting: Option<GameResult>,
     pub scores: HashMap<String, Score>,
     pub total_games: usize,
+    /// Games the engine could not play, they are not in `total_games`.
+    #[serde(default)]
+    pub failed_game_count: usize,
+    /// The first `MAX_KEPT_FAILURES` failed games.
+    #[serde(skip)]
+    pub failed_games: Vec<FailedGame>,
+    #[serde(default)]
+    pub rating_system: RatingSystemKind,
+    #[serde(default)]
+    pub ratings: HashMap<String, Rating>,
+    /// Every game in `ratings`, so `merge_with` can rate the games of both results in one table.
+    #[serde(skip)]
+    pub rated_games: Vec<RatedGame>,
+    /// Which games to keep in `lost_games` and `top_games`. They stay local and are not sent to the web front-end.
+    #[serde(skip)]
+    pub replay_selection: ReplaySelection,
+    #[serde(skip)]
+    pub lost_games: Vec<GameResult>,
+    /// Best scoring games first.
+    #[serde(skip)]
+    pub top_games: Vec<GameResult>,
 }
 
 
+impl Default for TournamentResult {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl TournamentResult {
     pub fn new() -> Self {
-        Self { most_interesting: None, scores: HashMap::new(), total_games: 0, }
+        Self::with_rating_system(RatingSystemKind::default())
+    }
+
+    pub fn with_rating_system(rating_system: RatingSystemKind) -> Self {
+        Self {
+            most_interesting: None,
+            scores: HashMap::new(),
+            total_games: 0,
+            failed_game_count: 0,
+            failed_games: Vec::new(),
+            rating_system,
+            ratings: HashMap::new(),
+            rated_games: Vec::new(),
+            replay_selection: ReplaySelection::default(),
+            lost_games: Vec::new(),
+            top_games: Vec::new(),
+        }
     }
+
+    pub fn with_replay_selection(self, replay_selection: ReplaySelection) -> Self {
+        Self { replay_selection, ..self }
+    }
+
+    /// Keeps the game when `replay_selection` asks for it. `scores[i]` is the score of `botnames[i]`.
+    pub fn keep_replay(&mut self, game_result: &GameResult, botnames: &[String], scores: &[Score]) {
+        let selection = &self.replay_selection;
+        let lost = botnames.iter().zip(scores).any(|(name, score)| score.losses > 0 && selection.losses_of.contains(name));
+        if lost && self.lost_games.len() < selection.max_losses {
+            self.lost_games.push(game_result.clone());
+        }
+        if selection.top > 0 {
+            self.top_games.push(game_result.clone());
+            self.trim_top_games(selection.top);
+        }
+    }
+
+    /// All kept games, each game once.
+    pub fn saved_games(&self) -> Vec<&GameResult> {
+        let mut games: Vec<&GameResult> = Vec::new();
+        for game in self.lost_games.iter().chain(&self.top_games) {
+            if !games.iter().any(|kept| kept.seed == game.seed) {
+                games.push(game);
+            }
+        }
+        games
+    }
+
+    fn trim_top_games(&mut self, top: usize) {
+        self.top_games.sort_by_key(|game| std::cmp::Reverse(game.score));
+        self.top_games.truncate(top);
+    }
+
+    /// Records a game the engine could not play.
+    pub fn add_failed_game(&mut self, config: &GameConfig, error: GameError) {
+        self.failed_game_count += 1;
+        if self.failed_games.len() < MAX_KEPT_FAILURES {
+            self.failed_games.push(FailedGame { config: config.clone(), error });
+        }
+    }
+
     pub fn add_score(&mut self, botname: &String, score_to_add: Score) {
         self.scores
             .entry(botname.to_string())
             .and_modify(|score| {
                 score.wins += score_to_add.wins;
                 score.losses += score_to_add.losses;
+                score.draws += score_to_add.draws;
                 score.total_games += score_to_add.total_games;
+                score.timeouts += score_to_add.timeouts;
+                score.panics += score_to_add.panics;
+                score.kills += score_to_add.kills;
+                score.suicides += score_to_add.suicides;
             })
             .or_insert(score_to_add);
     }
 
-    pub fn merge_with(&mut self, other: &mut TournamentResult) {
+    /// Updates the ratings of the bots in a game. `places[i]` is the finishing place of `botnames[i]`.
+    pub fn add_ratings(&mut self, botnames: &[String], places: &[usize]) {
+        let system = self.rating_system.system();
+        let ratings: Vec<Rating> = botnames
+            .iter()
+            .map(|name| self.ratings.get(name).copied().unwrap_or_else(|| system.initial_rating()))
+            .collect();
+
+        for (name, rating) in botnames.iter().zip(system.rate(&ratings, places)) {
+            self.ratings.insert(name.clone(), rating);
+        }
+        self.rated_games.push(RatedGame { botnames: botnames.to_vec(), places: places.to_vec() });
+    }
+
+    /// Adds the results of `other`, which must use the same rating system. The games of `other`
+    /// are rated after the games of this result, as if they were all played in one table.
+    pub fn merge_with(&mut self, other: &mut TournamentResult) -> Result<(), MergeError> {
+        if self.rating_system != other.rating_system {
+            return Err(MergeError::DifferentRatingSystems { ours: self.rating_system, theirs: other.rating_system });
+        }
+
         for (botname, score) in other.scores.iter() {
             self.add_score(botname, *score);
         }
 
+        for game in std::mem::take(&mut other.rated_games) {
+            self.add_ratings(&game.botnames, &game.places);
+        }
+
         if self.most_interesting.is_none() ||
             (other.most_interesting.is_some() && self.most_interesting.as_ref().unwrap().replay_data[0].len() < other.most_interesting.as_ref().unwrap().replay_data[0].len())
         {
@@ -45,5 +192,16 @@ impl TournamentResult {
 
 
         self.total_games += other.total_games;
+        self.failed_game_count += other.failed_game_count;
+        self.failed_games.append(&mut other.failed_games);
+        self.failed_games.truncate(MAX_KEPT_FAILURES);
+
+        self.lost_games.append(&mut other.lost_games);
+        let max_losses = self.replay_selection.max_losses.max(other.replay_selection.max_losses);
+        self.lost_games.truncate(max_losses);
+        self.top_games.append(&mut other.top_games);
+        let top = self.replay_selection.top.max(other.replay_selection.top);
+        self.trim_top_games(top);
+        Ok(())
     }
 }
\ No newline at end of file
@@ -1,5 +1,19 @@
 use bots::random_bot::RandomBot;
 use game::{bot::bot::BotController, game::game::Game, map::structs::map_config::MapConfig};
+use game::game::game_error::GameError;
+use game::game::replay_file::ReplayFile;
+use game::game::replay_format::ReplayFormat;
+use game::map::enums::map_config_error::MapConfigError;
+use game::map::enums::shrink_pattern::ShrinkPattern;
+use game::map::structs::map_generation_config::MapGenerationConfig;
+use game::map::structs::map_layout::MapLayout;
+use game::map::structs::time_budget::TimeBudget;
+use tournament::factories::game_config_factory::{ConfigFactory, GameConfig};
+use tournament::tournament::run_tournament_game;
+use tournament::replay_selection::ReplaySelection;
+use tournament::rating::rating::RatingSystemKind;
+use tournament::merge_error::MergeError;
+use tournament::tournament_result::TournamentResult;
 
 #[test]
 fn integration_game_runs_and_has_winner() {
@@ -12,14 +26,15 @@ fn integration_game_runs_and_has_winner() {
             bomb_timer: 4,
             bomb_radius: 3,
             endgame: 500,
-            size: 7
+            size: 7,
+            ..Default::default()
         };
-    let mut game = Game::build(vec![bot1, bot2], settings, None);
+    let mut game = Game::build(vec![bot1, bot2], settings, None).unwrap();
 
     // Act: speel maximaal 100 rondes of tot er een winnaar is
     let mut rounds = 0;
-    while !game.map.has_winner() && rounds < 1000 {
-        game.run_round( None);
+    while !game.map.is_game_over() && rounds < 1000 {
+        game.run_round( None).unwrap();
         rounds += 1;
     }
 
@@ -46,15 +61,196 @@ fn test() {
             bomb_timer: 4,
             bomb_radius: 3,
             endgame: 500,
-            size: 7
+            size: 7,
+            ..Default::default()
         };
 
     // Start een spel met een kleine map zodat het snel klaar is
-    let mut game = Game::build(  vec![bot1, bot2], settings, None);
+    let mut game = Game::build(  vec![bot1, bot2], settings, None).unwrap();
 
-    let x = game.run().replay_data;
+    let x = game.run().unwrap().replay_data;
 
     println!("Winnaar: {:?}", x);
     println!("Winnaar: {:?}", x);
 
 }
+
+#[test]
+fn integration_same_seed_gives_same_game() {
+    // Arrange: twee keer dezelfde bots, config en seed
+    let bot_constructors = bots::available_bots();
+    let config = GameConfig {
+        num_players: 4,
+        width: 11,
+        height: 11,
+        seed: 42,
+        time_budget: TimeBudget::default(),
+        layout: None,
+        generation: Some(MapGenerationConfig::default()),
+        shrink: ShrinkPattern::Rings { interval: 5 },
+    };
+    let mut first = TournamentResult::new();
+    let mut second = TournamentResult::new();
+
+    // Act
+    run_tournament_game(&mut first, &bot_constructors, &None, &config).unwrap();
+    run_tournament_game(&mut second, &bot_constructors, &None, &config).unwrap();
+
+    // Assert: beide spellen moeten precies hetzelfde verlopen
+    let first = first.most_interesting.unwrap();
+    let second = second.most_interesting.unwrap();
+    assert_eq!(first.seed, 42);
+    assert_eq!(first.winner, second.winner);
+    assert_eq!(first.replay_data, second.replay_data);
+}
+
+#[test]
+fn integration_bundled_maps_are_playable() {
+    // Arrange: laad alle kaarten uit de maps map
+    let maps_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("../maps");
+    let layouts: Vec<MapLayout> = std::fs::read_dir(maps_dir)
+        .unwrap()
+        .map(|entry| MapLayout::load(&entry.unwrap().path()).unwrap())
+        .collect();
+    let bot_constructors = bots::available_bots();
+
+    // Act & Assert: elke kaart start de spelers op hun spawn en speelt een heel spel
+    assert!(!layouts.is_empty());
+    for config in ConfigFactory::generate_layout_configs(&layouts) {
+        let layout = config.layout.clone().unwrap();
+        let bots = tournament::tournament::prepare_bots(&bot_constructors, config.num_players, &mut rand::rng());
+        let settings = MapConfig { width: Some(layout.width), height: Some(layout.height), layout: Some(layout.clone()), ..Default::default() };
+        let game = Game::build(bots, settings, None).unwrap();
+        let starts: Vec<_> = game.map.players.iter().map(|player| player.position).collect();
+        assert_eq!(starts, layout.spawns[..config.num_players]);
+
+        let mut result = TournamentResult::new();
+        run_tournament_game(&mut result, &bot_constructors, &None, &config).unwrap();
+        assert_eq!(result.total_games, 1, "{}", layout.name());
+    }
+}
+
+#[test]
+fn integration_wide_maps_run_with_all_bots() {
+    // Arrange: brede kaarten met vier spelers
+    let bot_constructors = bots::available_bots();
+    let wide: Vec<GameConfig> = ConfigFactory::generate_tournament_configs()
+        .into_iter()
+        .filter(|config| config.width > config.height && config.num_players == 4)
+        .collect();
+    let mut result = TournamentResult::new();
+
+    // Act: speel een paar spellen per kaart, zodat elke bot een keer meedoet
+    for (seed, config) in (0..3).flat_map(|seed| wide.iter().map(move |config| (seed, config))) {
+        run_tournament_game(&mut result, &bot_constructors, &None, &GameConfig { seed, ..config.clone() }).unwrap();
+    }
+
+    // Assert
+    assert!(!wide.is_empty());
+    assert_eq!(result.total_games, wide.len() * 3);
+    assert_eq!(result.scores.values().map(|score| score.panics).sum::<usize>(), 0);
+}
+
+#[test]
+fn integration_invalid_settings_are_reported_instead_of_panicking() {
+    // Arrange: alle toernooi configuraties en een spel op een kaart met een even breedte
+    let configs = ConfigFactory::generate_tournament_configs();
+    let bots = vec![
+        BotController::new(Box::new(RandomBot::new()), "Test1".to_string()),
+        BotController::new(Box::new(RandomBot::new()), "Test2".to_string()),
+    ];
+
+    // Act
+    let result = tournament::tournament::run_game(bots, 12, 11, 7);
+
+    // Assert: de toernooi kaarten zijn allemaal speelbaar, de even kaart geeft een fout
+    assert!(configs.iter().all(|config| config.validate().is_ok()));
+    assert!(matches!(result, Err(GameError::InvalidConfig(MapConfigError::InvalidSize { width: 12, height: 11 }))));
+}
+
+#[test]
+fn integration_selected_games_are_kept_and_replay_from_file() {
+    // Arrange: bewaar hooguit drie verloren spellen van alle bots en de twee beste spellen
+    let bot_constructors = bots::available_bots();
+    let names: Vec<String> = bot_constructors.iter().map(|constructor| constructor().get_name().split(' ').next().unwrap().to_string()).collect();
+    let config = ConfigFactory::generate_tournament_configs().into_iter().find(|config| config.width == 11 && config.num_players == 4).unwrap();
+    let selection = ReplaySelection { losses_of: names, max_losses: 3, top: 2 };
+    let mut result = TournamentResult::new().with_replay_selection(selection);
+
+    // Act
+    for seed in 0..4 {
+        run_tournament_game(&mut result, &bot_constructors, &None, &GameConfig { seed, ..config.clone() }).unwrap();
+    }
+
+    // Assert: elk spel heeft verliezers maar er worden er maar drie bewaard, de beste spellen staan vooraan
+    assert_eq!(result.lost_games.len(), 3);
+    assert_eq!(result.top_games.len(), 2);
+    assert!(result.top_games[0].score >= result.top_games[1].score);
+    assert!((3..=4).contains(&result.saved_games().len()));
+
+    // Assert: een bewaard spel speelt vanuit een replay bestand hetzelfde af
+    let game = result.top_games[0].clone();
+    let bytes = ReplayFile::from_result(&game).to_bytes(ReplayFormat::Binary).unwrap();
+    let replayed = ReplayFile::from_bytes(&bytes).unwrap().replay().unwrap();
+    assert_eq!(replayed.winner, game.winner);
+    assert_eq!(replayed.rounds, game.rounds);
+}
+
+#[test]
+fn integration_merging_different_rating_systems_fails() {
+    // Arrange: Elo en TrueSkill ratings zijn niet met elkaar te middelen
+    let mut elo = TournamentResult::with_rating_system(RatingSystemKind::Elo);
+    let mut trueskill = TournamentResult::with_rating_system(RatingSystemKind::TrueSkill);
+
+    // Act
+    let result = elo.merge_with(&mut trueskill);
+
+    // Assert
+    assert_eq!(result, Err(MergeError::DifferentRatingSystems { ours: RatingSystemKind::Elo, theirs: RatingSystemKind::TrueSkill }));
+}
+
+#[test]
+fn integration_merged_ratings_equal_rating_all_games_in_one_table() {
+    // Arrange: dezelfde spellen verdeeld over twee threads en in een enkele tabel
+    let games = [
+        (vec!["A", "B"], vec![0, 1]),
+        (vec!["B", "C", "A"], vec![0, 1, 1]),
+        (vec!["C", "A"], vec![0, 1]),
+        (vec!["A", "B", "C"], vec![2, 0, 1]),
+    ];
+    let rate = |result: &mut TournamentResult, games: &[(Vec<&str>, Vec<usize>)]| {
+        for (bots, places) in games {
+            let names: Vec<String> = bots.iter().map(|bot| bot.to_string()).collect();
+            result.add_ratings(&names, places);
+        }
+    };
+    let mut first = TournamentResult::with_rating_system(RatingSystemKind::Elo);
+    let mut second = TournamentResult::with_rating_system(RatingSystemKind::Elo);
+    let mut single_table = TournamentResult::with_rating_system(RatingSystemKind::Elo);
+    rate(&mut first, &games[..2]);
+    rate(&mut second, &games[2..]);
+    rate(&mut single_table, &games);
+
+    // Act
+    first.merge_with(&mut second).unwrap();
+
+    // Assert
+    assert_eq!(first.ratings, single_table.ratings);
+}
+
+#[test]
+fn integration_failed_games_are_recorded_and_the_tournament_goes_on() {
+    // Arrange: een onspeelbare even kaart tussen de gewone kaarten
+    let bot_constructors = bots::available_bots();
+    let good = ConfigFactory::generate_tournament_configs().into_iter().find(|config| config.width == 7 && config.num_players == 2).unwrap();
+    let bad = GameConfig { width: 8, height: 8, ..good.clone() };
+
+    // Act
+    let result = tournament::tournament::run_tournament(&bot_constructors, None, std::time::Duration::from_millis(200), vec![bad, good]);
+
+    // Assert: de gespeelde spellen blijven bewaard, het mislukte spel staat erbij met zijn fout
+    assert!(result.total_games > 0);
+    assert!(result.failed_game_count > 0);
+    assert_eq!(result.failed_games[0].config.width, 8);
+    assert!(matches!(result.failed_games[0].error, GameError::InvalidConfig(MapConfigError::InvalidSize { width: 8, height: 8 })));
+}
@@ -9,6 +9,7 @@ use leptos_router::{
 use crate::pages::home::home_page::HomePage;
 use crate::pages::game::game_config_page::GameConfigPage;
 use crate::pages::game::game_run_page::GameRunPage;
+use crate::pages::replay::replay_page::ReplayPage;
 use crate::pages::tournament::tournament_page::TournamentPage;
 
 #[component]
@@ -31,6 +32,7 @@ pub fn App() -> impl IntoView {
                     <Route path=path!("/game/config") view=GameConfigPage/>
                     <Route path=path!("/game/run") view=GameRunPage/>
                     <Route path=path!("/tournament") view=TournamentPage/>
+                    <Route path=path!("/replay") view=ReplayPage/>
                 </Routes>
             </main>
         </Router>
@@ -2,28 +2,36 @@ use leptos::prelude::*;
 use crate::shared_components::bot_selector::BotSelector;
 use crate::shared_components::bomberman_logo::BombermanLogo;
 use crate::shared_components::link::Link;
+use crate::shared_components::map_layout_selector::{bundled_layouts, MapLayoutSelector};
 use crate::shared_components::map_size_selector::MapSizeSelector;
 
 #[component]
 pub fn GameConfigPage() -> impl IntoView {
     let (selected_bots, set_selected_bots) = signal::<Vec<usize>>(Vec::new());
     let map_sizes = odd_numbers_in_range(7, 20);
-    let (grid_size, set_grid_size) = signal(map_sizes[2]);
+    let (grid_width, set_grid_width) = signal(map_sizes[2]);
+    let (grid_height, set_grid_height) = signal(map_sizes[2]);
+    let (map_layout, set_map_layout) = signal::<Option<usize>>(None);
+    let layouts = bundled_layouts();
 
     provide_context(selected_bots);
     provide_context(set_selected_bots);
     provide_context(map_sizes);
-    provide_context(grid_size);
-    provide_context(set_grid_size);
+    provide_context(map_layout);
+    provide_context(set_map_layout);
 
 view! {
     <div class="flex items-center justify-center w-full max-w-5xl gap-12">
         <div class="flex flex-col items-center gap-4">
             <BombermanLogo />
             {move || {
-                let size = grid_size.get();
+                let width = grid_width.get();
+                let height = grid_height.get();
                 let count = selected_bots.get().len();
-                let disabled = count < 2 || count > 4;
+                let layout = map_layout.get();
+                let max_players = layout.map_or(4, |index| layouts[index].spawns.len().min(4));
+                let disabled = !(2..=max_players).contains(&count);
+                let map_param = layout.map_or(String::new(), |index| format!("&map={index}"));
                 let bots_param = selected_bots
                     .get()
                     .iter()
@@ -33,7 +41,7 @@ view! {
                 view! {
                     <Link 
                         text="Start!".to_string() 
-                        link=format!("/game/run?bots={bots_param}&size={size}") 
+                        link=format!("/game/run?bots={bots_param}&width={width}&height={height}{map_param}") 
                         is_disabled=disabled 
                     />
                 }
@@ -41,7 +49,11 @@ view! {
         </div>
         <div class="flex flex-col justify-center gap-4">
             <BotSelector />
-            <MapSizeSelector />
+            <MapLayoutSelector />
+            <Show when=move || map_layout.get().is_none()>
+                <MapSizeSelector label="Choose your grid width" grid_size=grid_width set_grid_size=set_grid_width />
+                <MapSizeSelector label="Choose your grid height" grid_size=grid_height set_grid_size=set_grid_height />
+            </Show>
         </div>
     </div>
 }
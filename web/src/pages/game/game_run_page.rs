@@ -1,15 +1,21 @@
 use leptos::prelude::*;
+use rand::Rng;
 use leptos_router::hooks::use_query;
 use leptos_router::params::Params;
 use tournament::tournament::*;
 use bots::available_bots;
 use crate::shared_components::game_runner::run_game_result::RunGameResult;
+use crate::shared_components::map_layout_selector::bundled_layouts;
 
 
 #[derive(Params, PartialEq, Clone)]
 struct GameParams {
     bots: Option<String>,
     size: Option<usize>,
+    width: Option<usize>,
+    height: Option<usize>,
+    seed: Option<u64>,
+    map: Option<usize>,
 }
 #[component]
 pub fn GameRunPage() -> impl IntoView {
@@ -22,6 +28,9 @@ pub fn GameRunPage() -> impl IntoView {
             let bot_constructors = available_bots();
             let params = params_opt.expect("no params");
             let size = params.size.unwrap_or(11);
+            let width = params.width.unwrap_or(size);
+            let height = params.height.unwrap_or(size);
+            let seed = params.seed.unwrap_or_else(|| rand::rng().random());
             let bots = params.bots.as_ref()
                 .map(|s: &String| {
                     s.split(',')
@@ -31,7 +40,12 @@ pub fn GameRunPage() -> impl IntoView {
                 })
                 .unwrap_or_default();
 
-            run_game(bots, size)
+            let result = match params.map.and_then(|index| bundled_layouts().into_iter().nth(index)) {
+                Some(layout) => run_game_on_layout(bots, layout, seed),
+                None => run_game(bots, width, height, seed),
+            };
+            // Settings come from the url, so report them instead of crashing the app
+            result.map_err(|err| err.to_string())
         }
     );
 
@@ -40,9 +54,9 @@ pub fn GameRunPage() -> impl IntoView {
             fallback=move || view! { <p class="text-white">"Loading game..."</p> }
         >
             {move || Suspend::new(async move {
-                let result = game_result.get();
-                view! {
-                    <RunGameResult game_result=result.expect("suspensed")/>
+                match game_result.get().expect("suspensed") {
+                    Ok(result) => view! { <RunGameResult game_result=result/> }.into_any(),
+                    Err(err) => view! { <p class="text-white">"Cannot run this game: " {err}</p> }.into_any(),
                 }
             })}
         </Suspense>
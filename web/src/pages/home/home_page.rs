@@ -15,6 +15,7 @@ pub fn HomePage() -> impl IntoView {
             <div class="flex gap-4">
                 <Link text="Start tournament!".to_string() link="/tournament".to_string()/>
                 <Link text="Start game!".to_string() link="/game/config".to_string()/>
+                <Link text="Watch replay!".to_string() link="/replay".to_string()/>
             </div>
         </div>
     }
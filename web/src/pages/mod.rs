@@ -1,3 +1,4 @@
 pub mod home;
 pub mod game;
+pub mod replay;
 pub mod tournament;
\ No newline at end of file
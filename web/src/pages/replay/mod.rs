@@ -0,0 +1 @@
+pub mod replay_page;
\ No newline at end of file
@@ -0,0 +1,41 @@
+use leptos::prelude::*;
+use leptos::ev::Event;
+use gloo_file::futures::read_as_bytes;
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlInputElement;
+use game::game::game_result::GameResult;
+use game::game::replay_file::ReplayFile;
+use crate::shared_components::game_runner::run_game_result::RunGameResult;
+
+/// Plays a replay file saved by the CLI.
+#[component]
+pub fn ReplayPage() -> impl IntoView {
+    let (replay, set_replay) = signal(None::<Result<GameResult, String>>);
+
+    let on_change = move |event: Event| {
+        let input: HtmlInputElement = event_target(&event);
+        let Some(file) = input.files().and_then(|files| files.get(0)) else {
+            return;
+        };
+        spawn_local(async move {
+            let result = match read_as_bytes(&gloo_file::File::from(file)).await {
+                Ok(bytes) => ReplayFile::from_bytes(&bytes)
+                    .map_err(|err| err.to_string())
+                    .and_then(|replay| replay.replay().map_err(|err| err.to_string())),
+                Err(err) => Err(err.to_string()),
+            };
+            set_replay.set(Some(result));
+        });
+    };
+
+    view! {
+        <div class="flex flex-col items-center gap-4">
+            <input type="file" accept=".json,.bin" class="text-white" on:change=on_change/>
+            {move || match replay.get() {
+                None => ().into_any(),
+                Some(Ok(result)) => view! { <RunGameResult game_result=result/> }.into_any(),
+                Some(Err(err)) => view! { <p class="text-white">"Cannot load this replay: " {err}</p> }.into_any(),
+            }}
+        </div>
+    }
+}
@@ -14,12 +14,10 @@ pub fn TournamentPage() -> impl IntoView {
             .await
             .map_err(|err| err.to_string())?;
 
-        let json = response
+        response
             .json::<TournamentResult>()
             .await
-            .map_err(|_| "Failed to parse JSON".to_string());
-
-        json
+            .map_err(|_| "Failed to parse JSON".to_string())
     });
 
     view! {
@@ -31,9 +29,8 @@ pub fn TournamentPage() -> impl IntoView {
                     Some(Ok(result)) => {
                         let mut sorted_scores: Vec<_> = result.scores.iter().collect();
                         sorted_scores.sort_by(|a, b| {
-                            let a_wp = a.1.wins as f64 / a.1.total_games.max(1) as f64;
-                            let b_wp = b.1.wins as f64 / b.1.total_games.max(1) as f64;
-                            b_wp.partial_cmp(&a_wp).unwrap()
+                            let rating = |bot: &String| result.ratings.get(bot).map_or(f64::MIN, |rating| rating.conservative());
+                            rating(b.0).total_cmp(&rating(a.0))
                         });
 
                         view! {
@@ -45,11 +42,12 @@ pub fn TournamentPage() -> impl IntoView {
                                     <ul class="list-disc pl-6">
                                         {sorted_scores.iter().map(|(player, score)| {
                                             let win_pct = (score.wins as f64 / score.total_games.max(1) as f64) * 100.0;
+                                            let rating = result.ratings.get(*player).map_or("-".to_string(), |rating| rating.to_string());
                                             view! {
                                                 <li>
                                                     {format!(
-                                                        "{}: Win%: {:.1} (W:{} / L:{} / G:{})",
-                                                        player, win_pct, score.wins, score.losses, score.total_games
+                                                        "{}: Rating: {} Win%: {:.1} (W:{} / L:{} / D:{} / G:{} / Kills:{} / Suicides:{} / Timeouts:{} / Panics:{})",
+                                                        player, rating, win_pct, score.wins, score.losses, score.draws, score.total_games, score.kills, score.suicides, score.timeouts, score.panics
                                                     )}
                                                 </li>
                                             }
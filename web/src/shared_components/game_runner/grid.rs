@@ -5,14 +5,15 @@ use std::collections::HashMap;
 use super::tile::Tile;
 
 #[component]
-pub fn Grid(game_state: ReadSignal<MapReplaySnapshot>, width: usize) -> impl IntoView {
+pub fn Grid(game_state: ReadSignal<MapReplaySnapshot>) -> impl IntoView {
     view! {
         <div
             class="grid grid-cols-(--my-grid-cols) "
-            style=move || format!("--my-grid-cols: repeat({}, minmax(0, 1fr));", width)
+            style=move || format!("--my-grid-cols: repeat({}, minmax(0, 1fr));", game_state.get().width)
         >
             { move || {
                     let state = game_state.get();
+                    let width = state.width;
                     
                     let player_map: HashMap<usize, usize> = state.players
                         .iter()
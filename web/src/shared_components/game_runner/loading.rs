@@ -36,7 +36,7 @@ pub fn Loading() -> impl IntoView {
 
     view! {
         <p>"Loading..."</p>
-        <Grid game_state=game_state width=19/>    
+        <Grid game_state=game_state/>    
     }
 }
 
@@ -73,15 +73,15 @@ pub fn generate_loading_animation() -> Vec<MapReplaySnapshot> {
 
     for turn in 0..frames {
         let player_1_pos = if turn < player_1_path.len() {
-            player_1_path[turn].clone()
+            player_1_path[turn]
         } else {
-            player_1_path.last().unwrap().clone()
+            *player_1_path.last().unwrap()
         };
 
         let player_2_pos = if turn < player_2_path.len() {
-            player_2_path[turn].clone()
+            player_2_path[turn]
         } else {
-            player_2_path.last().unwrap().clone()
+            *player_2_path.last().unwrap()
         };
 
         snapshots.push(MapReplaySnapshot {
@@ -91,6 +91,8 @@ pub fn generate_loading_animation() -> Vec<MapReplaySnapshot> {
                 Player::new("Bob".to_string(), player_2_pos, 1),
             ],
             bombs: vec![],
+            width: 19,
+            height: 19,
             grid: grid.clone(),
             explosions: explosions.clone(),
         });
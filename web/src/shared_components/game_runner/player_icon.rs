@@ -29,7 +29,7 @@ pub fn PlayerIcon(index: usize, #[prop(optional)] is_dead: bool) -> impl IntoVie
                     </div>
                 }.into_any()
             } else {
-                view! {}.into_any()
+                ().into_any()
             }}
         </div>
     }
@@ -21,6 +21,16 @@ pub fn PlayerSection(game_result: GameResult, count: ReadSignal<usize>, game_sta
 
                             let command = &game_result.replay_data[player.id][safe_index];
                             let debug_info = &game_result.debug_data[player.id][safe_index];
+                            let standing = game_result.players.get(player.id).map_or(String::new(), |result| {
+                                format!(" - place {} (turn {})", result.placement, result.death_turn.unwrap_or(game_result.rounds))
+                            });
+                            let death = player.death_cause.map_or(String::new(), |cause| match player.killed_by {
+                                Some(killer) => {
+                                    let killer = players.iter().find(|other| other.id == killer).map_or(killer.to_string(), |other| other.name.clone());
+                                    format!("{cause}, killed by {killer}")
+                                }
+                                None => cause.to_string(),
+                            });
 
                             view! {
                                 <div class="flex flex-col w-full p-4 rounded-2xl shadow-md bg-gray-700/90 border border-gray-600 transition-colors duration-150 hover:bg-gray-600/90">
@@ -45,16 +55,16 @@ pub fn PlayerSection(game_result: GameResult, count: ReadSignal<usize>, game_sta
                                                 </p>
                                             }.into_any()
                                         } else {
-                                            view! {}.into_any()
+                                            ().into_any()
                                         }}
                                         {if !player.is_alive() {
                                             view! {
                                                 <p class="font-mono text-xs text-gray-400 bg-gray-800/50 rounded px-2 py-1">
-                                                    {player.reason_killed.to_string()}
+                                                    {format!("{}{}", death, standing)}
                                                 </p>
                                             }.into_any()
                                         } else {
-                                            view! {}.into_any()
+                                            ().into_any()
                                         }}
                                     </div>
                                 </div>
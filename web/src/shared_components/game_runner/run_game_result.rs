@@ -13,7 +13,12 @@ use super::speed_control::SpeedControl;
 #[component]
 pub fn RunGameResult(game_result: GameResult) -> impl IntoView {
     let game_result_clone_payer_section = game_result.clone();
-    let (game_replay, _set_replay) = signal(replay(&game_result));
+    // Results also come from the backend, so a replay that does not fit the engine is reported
+    let game_replay = match replay(&game_result) {
+        Ok(game_replay) => game_replay,
+        Err(err) => return view! { <p class="text-white">"Cannot replay this game: " {err.to_string()}</p> }.into_any(),
+    };
+    let (game_replay, _set_replay) = signal(game_replay);
     let (count, set_count) = signal(0);
     let (play, set_play) = signal(false);
     let (timer, set_timer) = signal(250);
@@ -22,7 +27,6 @@ pub fn RunGameResult(game_result: GameResult) -> impl IntoView {
     Effect::new(move |_| {
         play.get();
         spawn_local(async move {
-            let play = play.clone();
             while play.get() {
                 set_count.set(count.get() + 1);
                 sleep(Duration::from_millis(timer.get())).await;
@@ -45,10 +49,11 @@ pub fn RunGameResult(game_result: GameResult) -> impl IntoView {
                 <div class="text-center space-y-1">
                     <p class="text-xl font-semibold">"Winner: " {game_result.winner}</p>
                     <div class="text-gray-300">"Score: " {game_result.score}</div>
+                    <div class="text-gray-300">"Seed: " {game_result.seed}</div>
                     <div class="text-gray-300">"Round: " {count}</div>
                 </div>
 
-                <Grid game_state=game_state width=game_result.game_settings.size/>
+                <Grid game_state=game_state/>
             </div>
 
             <div class="flex flex-col justify-between bg-gray-800/90 text-white rounded-2xl shadow-xl border border-gray-700 p-8 min-h-120">
@@ -60,6 +65,6 @@ pub fn RunGameResult(game_result: GameResult) -> impl IntoView {
                 </div>
             </div>
         </div>
-    }
+    }.into_any()
 }
 
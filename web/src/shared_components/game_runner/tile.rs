@@ -13,9 +13,22 @@ pub fn Tile(tile_type: char, player: Option<usize>, bomb: Option<usize>, explosi
 
         _ => "w-8 h-8 bg-gray-300 relative",
     };
+    let power_up = match tile_type {
+        'b' => Some(("B+", "bg-red-500")),
+        'r' => Some(("R+", "bg-orange-500")),
+        's' => Some(("S+", "bg-sky-500")),
+        _ => None,
+    };
 
     view! {
         <div class={class}>
+            // Power-up badge (if any)
+            {power_up.map(|(label, color)| view! {
+                <div class={format!("absolute inset-1 rounded-full {color} text-white text-xs font-bold flex items-center justify-center")}>
+                    {label}
+                </div>
+            })}
+
             // Bomb circle (if any)
             {bomb.map(|_| view! {
                 <img src="/images/bomb.png" class="w-8 h-8 absolute" alt="Bomb" />
@@ -33,7 +33,7 @@ pub fn Link(
             "
             disabled=is_disabled
             on:click=move |_| {
-                navigate(&format!("{}", link),Default::default());
+                navigate(&link,Default::default());
             }
         >
             {text}
@@ -0,0 +1,48 @@
+use leptos::*;
+use leptos::prelude::*;
+use game::map::structs::map_layout::MapLayout;
+
+/// The hand-designed maps from the `maps` directory, bundled into the web app.
+pub fn bundled_layouts() -> Vec<MapLayout> {
+    [include_str!("../../../maps/arena.map"), include_str!("../../../maps/crossroads.map")]
+        .iter()
+        .map(|text| MapLayout::parse(text).expect("Bundled map is invalid"))
+        .collect()
+}
+
+#[component]
+pub fn MapLayoutSelector() -> impl IntoView {
+    let layouts = bundled_layouts();
+    let map_layout = use_context::<ReadSignal<Option<usize>>>()
+        .expect("missing `map_layout` context");
+    let set_map_layout = use_context::<WriteSignal<Option<usize>>>()
+        .expect("missing `set_map_layout` context");
+
+    view! {
+        <div class="flex flex-col items-center gap-2">
+            <span class="text-lg font-bold text-center text-gray-200 drop-shadow-lg gap-2">"Choose your map"</span>
+            <select
+                on:change:target=move |ev| {
+                    set_map_layout.set(ev.target().value().parse().ok());
+                }
+                prop:value=move || map_layout.get().map_or(String::new(), |index| index.to_string())
+                class="bg-gray-800 text-white font-semibold rounded-2xl px-4 py-3 
+                shadow-lg cursor-pointer border-2 border-gray-700 select-none 
+                text-center transition-all duration-300 hover:scale-105 focus:outline-none"
+            >
+                <option value="">"Generated"</option>
+                {
+                    layouts.iter().enumerate().map(|(index, layout)| {
+                        view! {
+                            <option
+                                value=index.to_string()
+                            >
+                                {format!("{} ({}x{})", layout.name(), layout.width, layout.height)}
+                            </option>
+                        }
+                    }).collect::<Vec<_>>()
+                }
+            </select>
+        </div>
+    }
+}
@@ -2,17 +2,13 @@ use leptos::*;
 use leptos::prelude::*;
 
 #[component]
-pub fn MapSizeSelector() -> impl IntoView {
+pub fn MapSizeSelector(label: &'static str, grid_size: ReadSignal<usize>, set_grid_size: WriteSignal<usize>) -> impl IntoView {
     let map_sizes = use_context::<Vec<usize>>()
         .expect("missing `map_sizes` context");
-    let grid_size = use_context::<ReadSignal<usize>>()
-        .expect("missing `grid_size` context");
-    let set_grid_size = use_context::<WriteSignal<usize>>()
-        .expect("missing `set_grid_size` context");
 
     view! { 
         <div class="flex flex-col items-center gap-2">
-            <span class="text-lg font-bold text-center text-gray-200 drop-shadow-lg gap-2">"Choose your grid size"</span>
+            <span class="text-lg font-bold text-center text-gray-200 drop-shadow-lg gap-2">{label}</span>
             <select
                 on:change:target=move |ev| {
                     set_grid_size.set(ev.target().value().parse().unwrap());
@@ -2,4 +2,5 @@ pub mod bomberman_logo;
 pub mod link;
 pub mod bot_selector;
 pub mod game_runner;
+pub mod map_layout_selector;
 pub mod map_size_selector;
\ No newline at end of file